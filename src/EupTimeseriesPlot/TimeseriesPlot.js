@@ -48,7 +48,7 @@ const TimeseriesPlot = () => {
     [0, 0],
   ]);
   const [groups, setGroups] = useState([]);
-  const [valuesList, setValuesList] = useState([]);
+  const [displayGroups, setDisplayGroups] = useState([]);
   const [processedData, setProcessedData] = useState([]);
   const [shouldShowLimit, setShouldShowLimit] = useState([]);
 
@@ -56,8 +56,8 @@ const TimeseriesPlot = () => {
     const result = main(data, config);
     setDomain(result.domain);
     setGroups(result.filterGroups);
-    setValuesList(result.groupsValues);
-    setProcessedData(result.preprocessGroups);
+    setDisplayGroups(result.displayGroups);
+    setProcessedData(result.displayGroups.flat());
     setShouldShowLimit(result.shouldShowLimit);
   }
 
@@ -120,6 +120,11 @@ const TimeseriesPlot = () => {
   let valueRangeMode = _get(config, 'valueRangeMode', 'minMax');
   valueRangeMode = isString(valueRangeMode) ? valueRangeMode : 'minMax';
 
+  // Get xMode config from config and validate it
+  let xMode = _get(config, 'xMode', 'index');
+  xMode = isString(xMode) ? xMode : 'index';
+  const timeMode = xMode === 'time';
+
   // Get drawLines config from config and validate it
   let configLines = _get(config, 'drawLines', false);
   configLines = isBoolean(configLines) ? configLines : false;
@@ -193,7 +198,7 @@ const TimeseriesPlot = () => {
 
   // Define x-axis
   const xDomain = domain[0];
-  const xScale = d3.scaleLinear().domain(xDomain);
+  const xScale = (timeMode ? d3.scaleTime() : d3.scaleLinear()).domain(xDomain);
 
   // Define y-axis
   const yDomain = domain[1];
@@ -282,29 +287,28 @@ const TimeseriesPlot = () => {
       return;
     }
 
-    function getXScale(xNumber) {
-      xScale.domain(currentXDomain);
-      return xScale.invert(xNumber);
-    }
-
-    function getYScale(yNumber) {
-      yScale.domain(currentYDomain);
-      return yScale.invert(yNumber);
-    }
+    xScale.domain(currentXDomain);
+    yScale.domain(currentYDomain);
 
-    const coordX = Math.abs(getXScale(coord.x));
-    const coordY = getYScale(coord.y);
+    // Compare in pixels, so the tolerance is the same on index and time axes
+    const hitRadius = Math.max(Math.sqrt(pointsSize / Math.PI), 4);
 
-    // find the closes datapoint to the pointer
+    // find the closest datapoint to the pointer
     let dataPoint;
+    let closestDistance = Infinity;
     for (let i = 0; i < processedData.length; i++) {
       const element = processedData[i];
 
-      const eleX = element.x;
-      const eleY = element.y;
+      const eleX = xScale(element.x);
+      const eleY = yScale(element.y);
+
+      if (checkApprox(eleX, coord.x, hitRadius) && checkApprox(eleY, coord.y, hitRadius)) {
+        const distance = Math.hypot(eleX - coord.x, eleY - coord.y);
 
-      if (checkApprox(eleX, coordX, 0.2) && checkApprox(eleY, coordY, 0.0001)) {
-        dataPoint = element;
+        if (distance < closestDistance) {
+          closestDistance = distance;
+          dataPoint = element;
+        }
       }
     }
 
@@ -591,14 +595,15 @@ const TimeseriesPlot = () => {
   // Define canvas points line
   const pointsLine = fc
     .seriesCanvasLine()
-    .crossValue((_, i) => i)
-    .mainValue((d) => d)
+    .crossValue((d) => d.x)
+    .mainValue((d) => d.y)
     .curve(d3.curveMonotoneX)
     .defined(() => true);
 
   // Create canvas points line
   const pointsLineSeries = fc
     .seriesCanvasRepeat()
+    .orient('horizontal')
     .series(pointsLine)
     .decorate((context) => {
       context.strokeStyle = 'transparent';
@@ -616,7 +621,7 @@ const TimeseriesPlot = () => {
     .chartCartesian(xScale, yScale)
     .yOrient('left')
     .yTickFormat((d) => `${formatSi(d, indexNotZero)}${units}`)
-    .xTickFormat((d) => `${formatNumber(d)}`)
+    .xTickFormat(timeMode ? null : (d) => `${formatNumber(d)}`)
     .canvasPlotArea(
       // Only render the annotations series on the Canvas layer
       fc
@@ -628,7 +633,7 @@ const TimeseriesPlot = () => {
             case gridlines:
               return null;
             case pointsLineSeries:
-              return d.valuesList;
+              return d.displayGroups;
             case limitsSeries:
               return d.limitData;
             case pointsSeries:
@@ -725,7 +730,7 @@ const TimeseriesPlot = () => {
     const data = processedData;
 
     d3.select(`#timeseries-plot-${id}`)
-      .datum({ data, valuesList, limitData })
+      .datum({ data, displayGroups, limitData })
       .call(chart);
   }
 
@@ -1036,11 +1041,13 @@ TimeseriesPlot.propTypes = {
     valueRangeMode: PropTypes.oneOf(['minMax', 'limit']), // minMax ,limit, (default minMax)
     hiddenGroups: PropTypes.array,
     limitHighlight: PropTypes.bool,
+    xMode: PropTypes.oneOf(['index', 'time']), // index, time (default index)
 
     // only timeseries
     drawLines: PropTypes.bool,
     marker: PropTypes.bool,
     markerSize: PropTypes.number,
+    maxPoints: PropTypes.number,
   }),
   data: PropTypes.shape({
     groups: PropTypes.arrayOf(
@@ -1063,6 +1070,7 @@ TimeseriesPlot.propTypes = {
           std: PropTypes.number,
         }),
         values: PropTypes.arrayOf(PropTypes.number),
+        timestamps: PropTypes.arrayOf(PropTypes.oneOfType([PropTypes.number, PropTypes.string])),
       }),
     ),
     stats: PropTypes.shape({
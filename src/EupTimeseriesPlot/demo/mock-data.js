@@ -5,11 +5,15 @@ const config = {
   hiddenGroups: [], // hidden group index list
   // hiddenGroups: [1, 3], // hidden group index list
   limitHighlight: false, //boolean
+  xMode: 'index', // index, time (x from group timestamps, default index)
+  palette: 'tableau10', // tableau10, category10, okabeIto, tolBright, none (colour of groups without color)
+  // displayUnit: 'mV', // V, A, Ω, Hz, s, W, dB with SI prefix (default info UNITS)
 
   // only timeseries
   drawLines: true,
   marker: true,
   markerSize: 3,
+  // maxPoints: 2000, // points drawn per group, min and max of equal time buckets (default all)
 };
 
 const data = {
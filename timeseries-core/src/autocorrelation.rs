@@ -0,0 +1,224 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use std::f64::consts::PI;
+
+use crate::{consts::*, statistics::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get sample autocorrelation of values (biased estimator)
+ * @method autocorrelation
+ * @param {array} values
+ * @param {number} maxLag
+ * @returns {array} - correlation for lags 0 to maxLag
+ */
+pub fn autocorrelation(values: &[f64], maxLag: usize) -> Vec<f64> {
+    let n = values.len();
+    let m = mean(values);
+    let denominator: f64 = values.iter().map(|v| (v - m).powi(2)).sum();
+
+    (0..=maxLag)
+        .map(|lag| {
+            if lag >= n || denominator == 0.0 {
+                return if lag == 0 { 1.0 } else { 0.0 };
+            }
+
+            let numerator: f64 = (0..n - lag)
+                .map(|i| (values[i] - m) * (values[i + lag] - m))
+                .sum();
+
+            numerator / denominator
+        })
+        .collect()
+}
+
+/**
+ * Durbin-Levinson recursion on autocorrelations
+ * @method durbinLevinson
+ * @param {array} acf - autocorrelation for lags 0 to order
+ * @param {number} order
+ * @returns {array} - [AR coefficients of the given order, partial autocorrelation for lags 0 to order,
+ *                     innovation variance relative to the series variance]
+ */
+pub fn durbinLevinson(acf: &[f64], order: usize) -> (Vec<f64>, Vec<f64>, f64) {
+    let mut phi: Vec<f64> = Vec::new();
+    let mut pacf: Vec<f64> = vec![1.0];
+    let mut innovation = 1.0;
+
+    for k in 1..=order.min(acf.len().saturating_sub(1)) {
+        let numerator = acf[k] - (0..k - 1).map(|j| phi[j] * acf[k - 1 - j]).sum::<f64>();
+        let reflection = if innovation > 0.0 {
+            numerator / innovation
+        } else {
+            0.0
+        };
+
+        let mut nextPhi: Vec<f64> = (0..k - 1)
+            .map(|j| phi[j] - reflection * phi[k - 2 - j])
+            .collect();
+        nextPhi.push(reflection);
+
+        phi = nextPhi;
+        innovation *= 1.0 - reflection * reflection;
+        pacf.push(reflection);
+    }
+
+    (phi, pacf, innovation)
+}
+
+/**
+ * In-place iterative radix-2 fast Fourier transform
+ * @method fft
+ * @param {array} real - length must be a power of two
+ * @param {array} imaginary - same length as real
+ */
+pub fn fft(real: &mut [f64], imaginary: &mut [f64]) {
+    let n = real.len();
+
+    // Bit reversal permutation
+    let mut j = 0;
+
+    for i in 1..n {
+        let mut bit = n >> 1;
+
+        while j & bit != 0 {
+            j ^= bit;
+            bit >>= 1;
+        }
+
+        j |= bit;
+
+        if i < j {
+            real.swap(i, j);
+            imaginary.swap(i, j);
+        }
+    }
+
+    let mut length = 2;
+
+    while length <= n {
+        let angle = -2.0 * PI / length as f64;
+
+        for start in (0..n).step_by(length) {
+            for k in 0..length / 2 {
+                let (sin, cos) = (angle * k as f64).sin_cos();
+                let even = start + k;
+                let odd = even + length / 2;
+
+                let oddReal = real[odd] * cos - imaginary[odd] * sin;
+                let oddImaginary = real[odd] * sin + imaginary[odd] * cos;
+
+                real[odd] = real[even] - oddReal;
+                imaginary[odd] = imaginary[even] - oddImaginary;
+                real[even] += oddReal;
+                imaginary[even] += oddImaginary;
+            }
+        }
+
+        length <<= 1;
+    }
+}
+
+/**
+ * Get periodogram of demeaned values, zero-padded to a power of two
+ * @method periodogram
+ * @param {array} values
+ * @returns {array} - [frequencies in cycles per sample, power]
+ */
+pub fn periodogram(values: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    if values.len() < 2 {
+        return (vec![], vec![]);
+    }
+
+    let m = mean(values);
+    let size = values.len().next_power_of_two();
+
+    let mut real: Vec<f64> = values.iter().map(|v| v - m).collect();
+    real.resize(size, 0.0);
+    let mut imaginary = vec![0.0; size];
+
+    fft(&mut real, &mut imaginary);
+
+    // Skip the zero frequency, it only holds the removed mean
+    (1..=size / 2)
+        .map(|k| {
+            (
+                k as f64 / size as f64,
+                (real[k].powi(2) + imaginary[k].powi(2)) / values.len() as f64,
+            )
+        })
+        .unzip()
+}
+
+/**
+ * Get ACF, PACF with confidence bounds and periodogram of values
+ * @method autocorrelationAnalysis
+ * @param {array} values
+ * @param {number} maxLag
+ * @param {number} alpha - significance level of the bounds
+ * @returns {object}
+ */
+pub fn autocorrelationAnalysis(values: &[f64], maxLag: usize, alpha: f64) -> AutocorrelationStruct {
+    let n = values.len().max(1) as f64;
+    let z = normalQuantile(1.0 - alpha / 2.0);
+
+    let acf = autocorrelation(values, maxLag);
+    let (_, pacf, _) = durbinLevinson(&acf, maxLag);
+
+    // Bartlett bounds grow with the autocorrelation of smaller lags
+    let mut acfBounds: Vec<f64> = vec![0.0];
+    let mut cumulative = 0.0;
+
+    for r in acf.iter().skip(1) {
+        acfBounds.push(z * ((1.0 + 2.0 * cumulative) / n).sqrt());
+        cumulative += r.powi(2);
+    }
+
+    let (frequencies, power) = periodogram(values);
+
+    let dominantPeriod = frequencies
+        .iter()
+        .zip(power.iter())
+        .max_by(|a, b| a.1.partial_cmp(b.1).unwrap())
+        .filter(|(_, p)| **p > 0.0)
+        .map(|(f, _)| 1.0 / f);
+
+    AutocorrelationStruct {
+        lags: (0..=maxLag as u32).collect(),
+        acf,
+        acfBounds,
+        pacf,
+        pacfBound: z / n.sqrt(),
+        frequencies,
+        periodogram: power,
+        dominantPeriod,
+    }
+}
+
+/**
+ * Get autocorrelation analysis of every group
+ * @method groupsAutocorrelation
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @returns {array} - empty when autocorrelationMaxLag is not set
+ */
+pub fn groupsAutocorrelation(
+    groups: Vec<GroupItemStruct>,
+    config: &ConfigStruct,
+) -> Vec<AutocorrelationStruct> {
+    let maxLag = match config.autocorrelationMaxLag {
+        Some(maxLag) => maxLag as usize,
+        None => return vec![],
+    };
+    let alpha = config
+        .autocorrelationAlpha
+        .unwrap_or(DEFAULT_AUTOCORRELATION_ALPHA);
+
+    groups
+        .into_iter()
+        .map(|group| autocorrelationAnalysis(&group.values.unwrap_or(vec![]), maxLag, alpha))
+        .collect()
+}
@@ -0,0 +1,164 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use std::io::{Read, Write};
+use timeseries_core::{csv_import::*, pipeline::*, raster::*, render::*, structs::*, summary::*};
+
+/* -------------- Define functions -------------- */
+
+const USAGE: &str =
+    "Usage: timeseries <data.json> <config.json> [--output result|stats|yield|svg|png] [--out FILE]
+
+  data.json    dataset in the DataStruct schema, - reads stdin,
+               .csv and .tsv files are read with the csv options of the config
+  config.json  config in the ConfigStruct schema
+  --output     what to write, result by default
+  --out        file to write, stdout by default";
+
+struct ArgsStruct {
+    dataPath: String,
+    configPath: String,
+    output: String,
+    outPath: Option<String>,
+}
+
+/**
+ * Parse command-line arguments
+ * @method parseArgs
+ * @param {array} args - arguments without the program name
+ * @returns {object} - error with the reason when arguments are invalid
+ */
+fn parseArgs(args: Vec<String>) -> Result<ArgsStruct, String> {
+    let mut positional: Vec<String> = Vec::new();
+    let mut output = "result".to_string();
+    let mut outPath: Option<String> = None;
+    let mut args = args.into_iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--output" => output = args.next().ok_or("--output needs a value")?,
+            "--out" => outPath = Some(args.next().ok_or("--out needs a value")?),
+            "-h" | "--help" => return Err(USAGE.to_string()),
+            _ if arg.starts_with("--") => return Err(format!("Unknown option {}", arg)),
+            _ => positional.push(arg),
+        }
+    }
+
+    if !["result", "stats", "yield", "svg", "png"].contains(&output.as_str()) {
+        return Err(format!("Unknown output {}", output));
+    }
+
+    match <[String; 2]>::try_from(positional) {
+        Ok([dataPath, configPath]) => Ok(ArgsStruct {
+            dataPath,
+            configPath,
+            output,
+            outPath,
+        }),
+        Err(_) => Err("Expected a data and a config file".to_string()),
+    }
+}
+
+/**
+ * Read a file, - reads stdin
+ * @method readInput
+ * @param {string} path
+ * @returns {string}
+ */
+fn readInput(path: &str) -> Result<String, String> {
+    if path == "-" {
+        let mut text = String::new();
+
+        std::io::stdin()
+            .read_to_string(&mut text)
+            .map_err(|e| format!("Can't read stdin: {}", e))?;
+
+        return Ok(text);
+    }
+
+    std::fs::read_to_string(path).map_err(|e| format!("Can't read {}: {}", path, e))
+}
+
+/**
+ * Serialize value to pretty JSON bytes
+ * @method toJson
+ * @param {object} value
+ * @returns {array}
+ */
+fn toJson<T: serde::Serialize>(value: &T) -> Result<Vec<u8>, String> {
+    serde_json::to_vec_pretty(value).map_err(|e| e.to_string())
+}
+
+/**
+ * Run the requested output on the dataset
+ * @method run
+ * @param {object} args
+ * @returns {array} - bytes to write
+ */
+fn run(args: &ArgsStruct) -> Result<Vec<u8>, String> {
+    let config: ConfigStruct = serde_json::from_str(&readInput(&args.configPath)?)
+        .map_err(|e| format!("Invalid config {}: {}", args.configPath, e))?;
+
+    let dataPath = args.dataPath.to_ascii_lowercase();
+    let data: DataStruct = if dataPath.ends_with(".csv") || dataPath.ends_with(".tsv") {
+        readCsv(&readInput(&args.dataPath)?, &config)
+            .map_err(|e| format!("Invalid data {}: {}", args.dataPath, e))?
+    } else {
+        serde_json::from_str(&readInput(&args.dataPath)?)
+            .map_err(|e| format!("Invalid data {}: {}", args.dataPath, e))?
+    };
+
+    match args.output.as_str() {
+        "svg" => Ok(renderSvg(data, &config)?.into_bytes()),
+        "png" => renderPng(data, &config),
+        output => {
+            let result = process(data, &config)?;
+            let loLimit = result.info.LO_LIMIT;
+            let hiLimit = result.info.HI_LIMIT;
+
+            // Warnings of the data go to stderr, the output stays machine readable
+            for warning in result.warnings.iter() {
+                eprintln!(
+                    "warning: group {:?} {}: {}",
+                    warning.group, warning.field, warning.message
+                );
+            }
+
+            match output {
+                // Same groups as the box plots, outliers are left out with excludeOutliers
+                "stats" => toJson(&groupsStats(result.statsGroups, loLimit, hiLimit)),
+                "yield" => toJson(&yieldSummary(result.statsGroups, loLimit, hiLimit)),
+                _ => toJson(&result),
+            }
+        }
+    }
+}
+
+fn main() {
+    let args = match parseArgs(std::env::args().skip(1).collect()) {
+        Ok(args) => args,
+        Err(message) if message == USAGE => {
+            eprintln!("{}", USAGE);
+            std::process::exit(0);
+        }
+        Err(message) => {
+            eprintln!("error: {}\n\n{}", message, USAGE);
+            std::process::exit(2);
+        }
+    };
+
+    let written = run(&args).and_then(|bytes| match &args.outPath {
+        Some(path) => {
+            std::fs::write(path, bytes).map_err(|e| format!("Can't write {}: {}", path, e))
+        }
+        None => std::io::stdout()
+            .write_all(&bytes)
+            .map_err(|e| format!("Can't write stdout: {}", e)),
+    });
+
+    if let Err(message) = written {
+        eprintln!("error: {}", message);
+        std::process::exit(1);
+    }
+}
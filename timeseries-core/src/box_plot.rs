@@ -0,0 +1,108 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get five-number summary, whiskers and outliers of a group
+ * @method boxPlot
+ * @param {object} group - group returned by filterGroup, NaN already removed
+ * @param {string} whiskerMode - "tukey" (1.5 x IQR) or "percentile"
+ * @param {array} whiskerPercentiles - [low, high] percentiles used in "percentile" mode
+ * @returns {object} - null when group has no values
+ */
+pub fn boxPlot(
+    group: &GroupItemStruct,
+    whiskerMode: &str,
+    whiskerPercentiles: [f64; 2],
+) -> Option<BoxPlotStruct> {
+    let values = group.values.clone().unwrap_or(vec![]);
+    let xValues = groupXValues(group);
+    let sorted = sortedValues(&values);
+
+    if sorted.is_empty() {
+        return None;
+    }
+
+    let min = sorted[0];
+    let max = sorted[sorted.len() - 1];
+    let q1 = quantile(&sorted, 0.25);
+    let median = median(&sorted);
+    let q3 = quantile(&sorted, 0.75);
+    let iqr = q3 - q1;
+
+    let (lowerWhisker, upperWhisker) = if whiskerMode == "percentile" {
+        (
+            quantile(&sorted, whiskerPercentiles[0] / 100.0),
+            quantile(&sorted, whiskerPercentiles[1] / 100.0),
+        )
+    } else {
+        let lowerFence = q1 - TUKEY_FACTOR * iqr;
+        let upperFence = q3 + TUKEY_FACTOR * iqr;
+
+        // Whiskers end at the most extreme values inside the fences
+        let lowerWhisker = sorted
+            .iter()
+            .copied()
+            .find(|v| *v >= lowerFence)
+            .unwrap_or(min);
+        let upperWhisker = sorted
+            .iter()
+            .rev()
+            .copied()
+            .find(|v| *v <= upperFence)
+            .unwrap_or(max);
+
+        (lowerWhisker, upperWhisker)
+    };
+
+    // Points outside the whiskers, keeping the same x as preprocess
+    let outliers: Vec<OutlierPointStruct> = values
+        .iter()
+        .enumerate()
+        .filter(|(_, v)| **v < lowerWhisker || **v > upperWhisker)
+        .map(|(i, v)| OutlierPointStruct {
+            x: xValues[i],
+            value: *v,
+        })
+        .collect();
+
+    Some(BoxPlotStruct {
+        keyValues: group
+            .keyValues
+            .unwrap_or(KeyValuesStruct { SITE_NUM: None }),
+        color: group.color.clone().unwrap_or(DEFAULT_COLOR.to_string()),
+        count: sorted.len() as u32,
+        min,
+        q1,
+        median,
+        q3,
+        max,
+        iqr,
+        lowerWhisker,
+        upperWhisker,
+        outliers,
+    })
+}
+
+/**
+ * Get box plot of every group
+ * @method boxPlots
+ * @param {array} groups - groups returned by filterGroup
+ * @param {string} whiskerMode
+ * @param {array} whiskerPercentiles
+ * @returns {array}
+ */
+pub fn boxPlots(
+    groups: Vec<GroupItemStruct>,
+    whiskerMode: String,
+    whiskerPercentiles: [f64; 2],
+) -> Vec<Option<BoxPlotStruct>> {
+    groups
+        .iter()
+        .map(|group| boxPlot(group, &whiskerMode, whiskerPercentiles))
+        .collect()
+}
@@ -0,0 +1,359 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get prefix sums of values and of squared values
+ * @method prefixSums
+ * @param {array} values
+ * @returns {array} - [sums, squares], both of length values.length + 1
+ */
+fn prefixSums(values: &[f64]) -> (Vec<f64>, Vec<f64>) {
+    let mut sums = vec![0.0; values.len() + 1];
+    let mut squares = vec![0.0; values.len() + 1];
+
+    for (i, value) in values.iter().enumerate() {
+        sums[i + 1] = sums[i] + value;
+        squares[i + 1] = squares[i] + value * value;
+    }
+
+    (sums, squares)
+}
+
+/**
+ * Get cost of segment [start, end) as twice the negative Gaussian log-likelihood
+ * @method segmentCost
+ * @param {array} sums - prefix sums of values
+ * @param {array} squares - prefix sums of squared values
+ * @param {number} start
+ * @param {number} end
+ * @param {string} cost - "mean" (change in mean) or "meanvar" (change in mean or variance)
+ * @param {number} scale - noise standard deviation used by "mean" cost
+ * @returns {number}
+ */
+fn segmentCost(
+    sums: &[f64],
+    squares: &[f64],
+    start: usize,
+    end: usize,
+    cost: &str,
+    scale: f64,
+) -> f64 {
+    let n = (end - start) as f64;
+    let sum = sums[end] - sums[start];
+    let squaredDeviation = (squares[end] - squares[start] - sum * sum / n).max(0.0);
+
+    if cost == "meanvar" {
+        let segmentVariance = (squaredDeviation / n).max(f64::EPSILON * scale * scale);
+
+        n * segmentVariance.ln()
+    } else {
+        squaredDeviation / (scale * scale)
+    }
+}
+
+/**
+ * Estimate noise standard deviation robustly from successive differences
+ * @method noiseScale
+ * @param {array} values
+ * @returns {number} - 0 when values are constant
+ */
+pub fn noiseScale(values: &[f64]) -> f64 {
+    let differences: Vec<f64> = values.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+    let scale = median(&sortedValues(&differences)) / (MAD_Z_SCALE * 2.0_f64.sqrt());
+
+    if scale > 0.0 {
+        return scale;
+    }
+
+    let std = standardDeviation(values);
+
+    if std.is_nan() {
+        0.0
+    } else {
+        std
+    }
+}
+
+/**
+ * Find change points by pruned exact linear time search (PELT)
+ * @method peltChangePoints
+ * @param {array} values
+ * @param {string} cost - "mean" or "meanvar"
+ * @param {number} penalty - cost added for every change point
+ * @param {number} minSize - minimum segment length
+ * @returns {array} - index of first value of every new segment
+ */
+pub fn peltChangePoints(values: &[f64], cost: &str, penalty: f64, minSize: usize) -> Vec<u32> {
+    let n = values.len();
+    let scale = noiseScale(values);
+
+    if n < 2 * minSize || scale == 0.0 {
+        return vec![];
+    }
+
+    let (sums, squares) = prefixSums(values);
+
+    // best[t] is the optimal cost of values[0..t], last[t] the last change before t
+    let mut best = vec![f64::INFINITY; n + 1];
+    let mut last = vec![0; n + 1];
+    let mut candidates: Vec<usize> = vec![0];
+
+    best[0] = -penalty;
+
+    for t in minSize..=n {
+        let mut costs: Vec<(usize, f64)> = Vec::new();
+
+        for s in candidates.iter().copied().filter(|s| t - s >= minSize) {
+            costs.push((
+                s,
+                best[s] + segmentCost(&sums, &squares, s, t, cost, scale) + penalty,
+            ));
+        }
+
+        if let Some((s, value)) = costs
+            .iter()
+            .copied()
+            .min_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+        {
+            best[t] = value;
+            last[t] = s;
+        }
+
+        // Prune candidates that can never be optimal again
+        candidates.retain(|s| {
+            t - s < minSize
+                || best[*s] + segmentCost(&sums, &squares, *s, t, cost, scale) <= best[t]
+        });
+
+        candidates.push(t);
+    }
+
+    let mut changePoints: Vec<u32> = Vec::new();
+    let mut t = n;
+
+    while t > 0 {
+        let s = last[t];
+
+        if s > 0 {
+            changePoints.push(s as u32);
+        }
+
+        t = s;
+    }
+
+    changePoints.reverse();
+
+    changePoints
+}
+
+/**
+ * Find change points by binary segmentation of the cost
+ * @method binarySegmentationChangePoints
+ * @param {array} values
+ * @param {string} cost - "mean" or "meanvar"
+ * @param {number} penalty - minimum cost decrease to accept a split
+ * @param {number} maxCount - maximum number of change points
+ * @param {number} minSize - minimum segment length
+ * @returns {array} - index of first value of every new segment
+ */
+pub fn binarySegmentationChangePoints(
+    values: &[f64],
+    cost: &str,
+    penalty: f64,
+    maxCount: usize,
+    minSize: usize,
+) -> Vec<u32> {
+    let n = values.len();
+    let scale = noiseScale(values);
+
+    if n < 2 * minSize || scale == 0.0 {
+        return vec![];
+    }
+
+    let (sums, squares) = prefixSums(values);
+    let mut segments: Vec<(usize, usize)> = vec![(0, n)];
+    let mut changePoints: Vec<u32> = Vec::new();
+
+    while changePoints.len() < maxCount {
+        // Best split over every current segment
+        let mut bestSplit: Option<(usize, usize, f64)> = None;
+
+        for (index, (start, end)) in segments.iter().copied().enumerate() {
+            if end - start < 2 * minSize {
+                continue;
+            }
+
+            let whole = segmentCost(&sums, &squares, start, end, cost, scale);
+
+            for split in (start + minSize)..=(end - minSize) {
+                let gain = whole
+                    - segmentCost(&sums, &squares, start, split, cost, scale)
+                    - segmentCost(&sums, &squares, split, end, cost, scale);
+
+                if bestSplit.is_none_or(|(_, _, bestGain)| gain > bestGain) {
+                    bestSplit = Some((index, split, gain));
+                }
+            }
+        }
+
+        match bestSplit {
+            Some((index, split, gain)) if gain > penalty => {
+                let (start, end) = segments.remove(index);
+
+                segments.push((start, split));
+                segments.push((split, end));
+                changePoints.push(split as u32);
+            }
+            _ => break,
+        }
+    }
+
+    changePoints.sort();
+
+    changePoints
+}
+
+/**
+ * Find change points in the mean by recursive CUSUM splitting
+ * @method cusumChangePoints
+ * @param {array} values
+ * @param {number} maxCount - maximum number of change points
+ * @param {number} minSize - minimum segment length
+ * @returns {array} - index of first value of every new segment
+ */
+pub fn cusumChangePoints(values: &[f64], maxCount: usize, minSize: usize) -> Vec<u32> {
+    let scale = noiseScale(values);
+
+    if values.len() < 2 * minSize || scale == 0.0 {
+        return vec![];
+    }
+
+    let mut pending: Vec<(usize, usize)> = vec![(0, values.len())];
+    let mut changePoints: Vec<u32> = Vec::new();
+
+    while let Some((start, end)) = pending.pop() {
+        if changePoints.len() >= maxCount || end - start < 2 * minSize {
+            continue;
+        }
+
+        let segment = &values[start..end];
+        let segmentMean = mean(segment);
+        let mut cumulative = 0.0;
+        let mut bestSplit: Option<(usize, f64)> = None;
+
+        for (i, value) in segment.iter().enumerate().take(segment.len() - minSize) {
+            cumulative += value - segmentMean;
+
+            if i + 1 >= minSize && bestSplit.is_none_or(|(_, best)| cumulative.abs() > best) {
+                bestSplit = Some((start + i + 1, cumulative.abs()));
+            }
+        }
+
+        if let Some((split, statistic)) = bestSplit {
+            // Normalised CUSUM behaves like the supremum of a Brownian bridge
+            if statistic / (scale * (segment.len() as f64).sqrt()) > CUSUM_CRITICAL {
+                changePoints.push(split as u32);
+                pending.push((start, split));
+                pending.push((split, end));
+            }
+        }
+    }
+
+    changePoints.sort();
+
+    changePoints
+}
+
+/**
+ * Get statistics of segments delimited by change points
+ * @method segmentsStats
+ * @param {array} values
+ * @param {array} changePoints
+ * @returns {array}
+ */
+pub fn segmentsStats(values: &[f64], changePoints: &[u32]) -> Vec<SegmentStruct> {
+    let mut bounds: Vec<usize> = vec![0];
+    bounds.extend(changePoints.iter().map(|c| *c as usize));
+    bounds.push(values.len());
+
+    bounds
+        .windows(2)
+        .filter(|w| w[1] > w[0])
+        .map(|w| {
+            let segment = &values[w[0]..w[1]];
+            let std = standardDeviation(segment);
+
+            SegmentStruct {
+                start: w[0] as u32,
+                end: w[1] as u32 - 1,
+                count: segment.len() as u32,
+                mean: mean(segment),
+                std: if std.is_nan() { 0.0 } else { std },
+            }
+        })
+        .collect()
+}
+
+/**
+ * Get change points and segment statistics of every group
+ * @method groupsChangePoints
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @returns {array}
+ */
+pub fn groupsChangePoints(
+    groups: Vec<GroupItemStruct>,
+    config: &ConfigStruct,
+) -> Vec<ChangePointsStruct> {
+    let method = config
+        .changePointMethod
+        .clone()
+        .unwrap_or(DEFAULT_CHANGE_POINT_METHOD.to_string());
+    let cost = config
+        .changePointCost
+        .clone()
+        .unwrap_or(DEFAULT_CHANGE_POINT_COST.to_string());
+    let minSize = config
+        .changePointMinSize
+        .unwrap_or(DEFAULT_CHANGE_POINT_MIN_SIZE)
+        .max(1) as usize;
+
+    if method == "none" {
+        return vec![];
+    }
+
+    groups
+        .into_iter()
+        .map(|group| {
+            let values = group.values.unwrap_or(vec![]);
+
+            // BIC-like default penalty
+            let penalty = config
+                .changePointPenalty
+                .unwrap_or(2.0 * (values.len().max(2) as f64).ln());
+            let maxCount = config
+                .changePointMaxCount
+                .map(|count| count as usize)
+                .unwrap_or(values.len());
+
+            let changePoints = match method.as_str() {
+                "pelt" => peltChangePoints(&values, &cost, penalty, minSize),
+                "binseg" => {
+                    binarySegmentationChangePoints(&values, &cost, penalty, maxCount, minSize)
+                }
+                "cusum" => cusumChangePoints(&values, maxCount, minSize),
+                _ => vec![],
+            };
+
+            ChangePointsStruct {
+                segments: segmentsStats(&values, &changePoints),
+                changePoints,
+            }
+        })
+        .collect()
+}
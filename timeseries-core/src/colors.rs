@@ -0,0 +1,197 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Parse hex digits of "#rgb", "#rgba", "#rrggbb" or "#rrggbbaa"
+ * @method parseHexColor
+ * @param {string} hex - digits without "#"
+ * @returns {array} - normalised RGBA, null when hex is not valid
+ */
+fn parseHexColor(hex: &str) -> Option<[f64; 4]> {
+    if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let channels: Vec<f64> = match hex.len() {
+        // Short form repeats every digit
+        3 | 4 => hex
+            .chars()
+            .map(|c| c.to_digit(16).unwrap() as f64 * 17.0)
+            .collect(),
+        6 | 8 => (0..hex.len())
+            .step_by(2)
+            .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap() as f64)
+            .collect(),
+        _ => return None,
+    };
+
+    Some([
+        channels[0] / 255.0,
+        channels[1] / 255.0,
+        channels[2] / 255.0,
+        channels.get(3).map(|alpha| alpha / 255.0).unwrap_or(1.0),
+    ])
+}
+
+/**
+ * Parse a number or a percentage of a colour function
+ * @method parseChannel
+ * @param {string} text
+ * @param {number} percentScale - value of 100%
+ * @returns {number} - null when text is not a number
+ */
+fn parseChannel(text: &str, percentScale: f64) -> Option<f64> {
+    match text.strip_suffix('%') {
+        Some(percent) => Some(percent.trim().parse::<f64>().ok()? / 100.0 * percentScale),
+        None => text.parse::<f64>().ok().filter(|value| value.is_finite()),
+    }
+}
+
+/**
+ * Convert HSL to RGB
+ * @method hslToRgb
+ * @param {number} hue - degrees
+ * @param {number} saturation - 0 to 1
+ * @param {number} lightness - 0 to 1
+ * @returns {array} - [r, g, b] from 0 to 1
+ */
+pub fn hslToRgb(hue: f64, saturation: f64, lightness: f64) -> [f64; 3] {
+    let hue = hue.rem_euclid(360.0);
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+    let second = chroma * (1.0 - ((hue / 60.0) % 2.0 - 1.0).abs());
+    let offset = lightness - chroma / 2.0;
+
+    let (r, g, b) = match (hue / 60.0) as u32 {
+        0 => (chroma, second, 0.0),
+        1 => (second, chroma, 0.0),
+        2 => (0.0, chroma, second),
+        3 => (0.0, second, chroma),
+        4 => (second, 0.0, chroma),
+        _ => (chroma, 0.0, second),
+    };
+
+    [r + offset, g + offset, b + offset]
+}
+
+/**
+ * Parse CSS colour: "#rgb", "#rgba", "#rrggbb", "#rrggbbaa", "rgb()", "rgba()", "hsl()", "hsla()" or a named colour
+ * @method parseColor
+ * @param {string} text
+ * @returns {array} - normalised RGBA for WebGL, error when text is not a valid colour
+ */
+pub fn parseColor(text: &str) -> Result<[f64; 4], String> {
+    let color = text.trim().to_ascii_lowercase();
+    let invalid = || format!("invalid color \"{}\"", text);
+
+    if let Some(hex) = color.strip_prefix('#') {
+        return parseHexColor(hex).ok_or_else(invalid);
+    }
+
+    if color == "transparent" {
+        return Ok([0.0, 0.0, 0.0, 0.0]);
+    }
+
+    if let Some(&(_, rgb)) = CSS_NAMED_COLORS.iter().find(|(name, _)| *name == color) {
+        return Ok([
+            ((rgb >> 16) & 0xff) as f64 / 255.0,
+            ((rgb >> 8) & 0xff) as f64 / 255.0,
+            (rgb & 0xff) as f64 / 255.0,
+            1.0,
+        ]);
+    }
+
+    // Colour functions, arguments separated by commas or by spaces with "/ alpha"
+    let (name, arguments) = color
+        .strip_suffix(')')
+        .and_then(|color| color.split_once('('))
+        .ok_or_else(invalid)?;
+
+    let arguments: Vec<&str> = arguments
+        .split([',', ' ', '/'])
+        .map(|argument| argument.trim())
+        .filter(|argument| !argument.is_empty())
+        .collect();
+
+    if arguments.len() != 3 && arguments.len() != 4 {
+        return Err(invalid());
+    }
+
+    let alpha = match arguments.get(3) {
+        Some(alpha) => parseChannel(alpha, 1.0).ok_or_else(invalid)?,
+        None => 1.0,
+    };
+
+    let [r, g, b] = match name {
+        "rgb" | "rgba" => {
+            let mut channels = [0.0; 3];
+
+            for (channel, argument) in channels.iter_mut().zip(arguments.iter()) {
+                *channel = parseChannel(argument, 255.0).ok_or_else(invalid)? / 255.0;
+            }
+
+            channels
+        }
+        "hsl" | "hsla" => {
+            let hue =
+                parseChannel(arguments[0].trim_end_matches("deg"), 360.0).ok_or_else(invalid)?;
+            let saturation = parseChannel(arguments[1], 1.0).ok_or_else(invalid)?;
+            let lightness = parseChannel(arguments[2], 1.0).ok_or_else(invalid)?;
+
+            hslToRgb(hue, saturation.clamp(0.0, 1.0), lightness.clamp(0.0, 1.0))
+        }
+        _ => return Err(invalid()),
+    };
+
+    Ok([
+        r.clamp(0.0, 1.0),
+        g.clamp(0.0, 1.0),
+        b.clamp(0.0, 1.0),
+        alpha.clamp(0.0, 1.0),
+    ])
+}
+
+/**
+ * Get WebGL colour of every group, white when a group has no valid color
+ * @method groupsGlColors
+ * @param {array} groups
+ * @returns {array} - normalised RGBA per group
+ */
+pub fn groupsGlColors(groups: Vec<GroupItemStruct>) -> Vec<[f64; 4]> {
+    groups
+        .iter()
+        .map(|group| {
+            group
+                .color
+                .as_deref()
+                .and_then(|color| parseColor(color).ok())
+                .unwrap_or(DEFAULT_GL_COLOR)
+        })
+        .collect()
+}
+
+/**
+ * Get warnings of groups with a color that can't be parsed
+ * @method colorWarnings
+ * @param {array} groups - groups of data
+ * @returns {array} - warnings, the group of a warning is its index in data
+ */
+pub fn colorWarnings(groups: &[GroupItemStruct]) -> Vec<WarningStruct> {
+    groups
+        .iter()
+        .enumerate()
+        .filter_map(|(i, group)| {
+            let message = parseColor(group.color.as_deref()?).err()?;
+
+            Some(WarningStruct {
+                group: Some(i as u32),
+                field: "color".to_string(),
+                message,
+            })
+        })
+        .collect()
+}
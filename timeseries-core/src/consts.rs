@@ -0,0 +1,428 @@
+/* -------------- Define constants -------------- */
+
+pub const DEFAULT_COLOR: &str = "#27ae60";
+
+pub const TUKEY_FACTOR: f64 = 1.5;
+
+pub const DEFAULT_WHISKER_MODE: &str = "tukey";
+
+pub const DEFAULT_WHISKER_PERCENTILES: [f64; 2] = [5.0, 95.0];
+
+pub const DEFAULT_OUTLIER_METHOD: &str = "none";
+
+pub const DEFAULT_OUTLIER_ALPHA: f64 = 0.05;
+
+pub const DEFAULT_MAD_THRESHOLD: f64 = 3.5;
+
+// Scale of the median absolute deviation to the standard deviation of a normal distribution
+pub const MAD_Z_SCALE: f64 = 0.6745;
+
+pub const DEFAULT_COMPARISON_ALPHA: f64 = 0.05;
+
+pub const DEFAULT_COMPARISON_CORRECTION: &str = "holm";
+
+pub const DEFAULT_CHANGE_POINT_METHOD: &str = "none";
+
+pub const DEFAULT_CHANGE_POINT_COST: &str = "mean";
+
+pub const DEFAULT_CHANGE_POINT_MIN_SIZE: u32 = 2;
+
+// Critical value of the supremum of a Brownian bridge at 5% significance
+pub const CUSUM_CRITICAL: f64 = 1.358;
+
+pub const DEFAULT_EWMA_LAMBDA: f64 = 0.2;
+
+pub const DEFAULT_EWMA_L: f64 = 3.0;
+
+pub const DEFAULT_CUSUM_K: f64 = 0.5;
+
+pub const DEFAULT_CUSUM_H: f64 = 5.0;
+
+// Bias correction of the average moving range of 2 points (d2)
+pub const MOVING_RANGE_D2: f64 = 1.128;
+
+pub const DEFAULT_SMOOTHING_WINDOW: u32 = 5;
+
+pub const DEFAULT_SMOOTHING_POLY_ORDER: u32 = 2;
+
+pub const DEFAULT_ROLLING_K: f64 = 2.0;
+
+pub const DEFAULT_X_MODE: &str = "index";
+
+pub const DEFAULT_RESAMPLE_BUCKET: &str = "hour";
+
+pub const DEFAULT_RESAMPLE_AGGREGATION: &str = "mean";
+
+pub const RESAMPLE_BUCKETS: [&str; 6] = ["minute", "hour", "shift", "day", "week", "month"];
+
+pub const RESAMPLE_AGGREGATIONS: [&str; 7] =
+    ["mean", "median", "min", "max", "count", "std", "yield"];
+
+pub const DEFAULT_SHIFT_HOURS: f64 = 8.0;
+
+pub const DEFAULT_SHIFT_START_HOUR: f64 = 6.0;
+
+pub const MINUTE_MS: f64 = 60_000.0;
+
+pub const HOUR_MS: f64 = 3_600_000.0;
+
+pub const DAY_MS: f64 = 86_400_000.0;
+
+pub const DEFAULT_AUTOCORRELATION_ALPHA: f64 = 0.05;
+
+pub const DEFAULT_FORECAST_MODEL: &str = "linear";
+
+pub const DEFAULT_HOLT_ALPHA: f64 = 0.5;
+
+pub const DEFAULT_HOLT_BETA: f64 = 0.1;
+
+pub const DEFAULT_AR_ORDER: u32 = 2;
+
+pub const DEFAULT_STL_SEASONAL_WINDOW: u32 = 7;
+
+pub const DEFAULT_STL_ROBUST_ITERATIONS: u32 = 0;
+
+pub const STL_INNER_ITERATIONS: usize = 2;
+
+// Relative difference allowed between provided stats and the values
+pub const DEFAULT_STATS_TOLERANCE: f64 = 0.01;
+
+pub const DEFAULT_PALETTE: &str = "tableau10";
+
+pub const PALETTE_TABLEAU10: [&str; 10] = [
+    "#4e79a7", "#f28e2b", "#e15759", "#76b7b2", "#59a14f", "#edc948", "#b07aa1", "#ff9da7",
+    "#9c755f", "#bab0ac",
+];
+
+pub const PALETTE_CATEGORY10: [&str; 10] = [
+    "#1f77b4", "#ff7f0e", "#2ca02c", "#d62728", "#9467bd", "#8c564b", "#e377c2", "#7f7f7f",
+    "#bcbd22", "#17becf",
+];
+
+// Colour-blind safe, Okabe & Ito (2008)
+pub const PALETTE_OKABE_ITO: [&str; 8] = [
+    "#e69f00", "#56b4e9", "#009e73", "#f0e442", "#0072b2", "#d55e00", "#cc79a7", "#000000",
+];
+
+// Colour-blind safe, Paul Tol bright scheme
+pub const PALETTE_TOL_BRIGHT: [&str; 7] = [
+    "#4477aa", "#ee6677", "#228833", "#ccbb44", "#66ccee", "#aa3377", "#bbbbbb",
+];
+
+// Colour of groups without a valid color, same as parseGlColor of the front-end
+pub const DEFAULT_GL_COLOR: [f64; 4] = [1.0, 1.0, 1.0, 1.0];
+
+pub const CSS_NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+pub const DEFAULT_TOOLTIP_FIELDS: [&str; 10] = [
+    "keyValues",
+    "Value",
+    "Cp",
+    "Cpk",
+    "Max",
+    "Mean",
+    "Min",
+    "Std",
+    "Margin",
+    "Violations",
+];
+
+// Area of a marker in px², same default as the front-end
+pub const DEFAULT_MARKER_SIZE: u32 = 20;
+
+pub const DEFAULT_PLOT_WIDTH: u32 = 800;
+
+pub const DEFAULT_PLOT_HEIGHT: u32 = 400;
+
+// Left, top, right and bottom space around the plot area for axes
+pub const PLOT_MARGINS: [f64; 4] = [72.0, 12.0, 16.0, 28.0];
+
+pub const AXIS_COLOR: &str = "#212121";
+
+pub const GRID_COLOR: &str = "#e0e0e0";
+
+pub const LIMIT_HATCH_COLOR: &str = "#ff0000";
+
+pub const TICK_FONT_SIZE: f64 = 11.0;
+
+// Pixels per inch of a CSS px, the unit of width and height
+pub const DEFAULT_DPI: f64 = 96.0;
+
+// Largest PNG in pixels, 8192 x 8192 takes 256 MB before encoding
+pub const MAX_PIXMAP_PIXELS: f64 = 67_108_864.0;
+
+// 5x8 bitmap font of printable ASCII from space to tilde, one byte per column,
+// least significant bit on top, row 7 for descenders
+pub const FONT_5X8: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x5F, 0x00, 0x00],
+    [0x00, 0x07, 0x00, 0x07, 0x00],
+    [0x14, 0x7F, 0x14, 0x7F, 0x14],
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12],
+    [0x23, 0x13, 0x08, 0x64, 0x62],
+    [0x36, 0x49, 0x56, 0x20, 0x50],
+    [0x00, 0x08, 0x07, 0x03, 0x00],
+    [0x00, 0x1C, 0x22, 0x41, 0x00],
+    [0x00, 0x41, 0x22, 0x1C, 0x00],
+    [0x2A, 0x1C, 0x7F, 0x1C, 0x2A],
+    [0x08, 0x08, 0x3E, 0x08, 0x08],
+    [0x00, 0x80, 0x70, 0x30, 0x00],
+    [0x08, 0x08, 0x08, 0x08, 0x08],
+    [0x00, 0x00, 0x60, 0x60, 0x00],
+    [0x20, 0x10, 0x08, 0x04, 0x02],
+    [0x3E, 0x51, 0x49, 0x45, 0x3E],
+    [0x00, 0x42, 0x7F, 0x40, 0x00],
+    [0x72, 0x49, 0x49, 0x49, 0x46],
+    [0x21, 0x41, 0x49, 0x4D, 0x33],
+    [0x18, 0x14, 0x12, 0x7F, 0x10],
+    [0x27, 0x45, 0x45, 0x45, 0x39],
+    [0x3C, 0x4A, 0x49, 0x49, 0x31],
+    [0x41, 0x21, 0x11, 0x09, 0x07],
+    [0x36, 0x49, 0x49, 0x49, 0x36],
+    [0x46, 0x49, 0x49, 0x29, 0x1E],
+    [0x00, 0x00, 0x14, 0x00, 0x00],
+    [0x00, 0x40, 0x34, 0x00, 0x00],
+    [0x00, 0x08, 0x14, 0x22, 0x41],
+    [0x14, 0x14, 0x14, 0x14, 0x14],
+    [0x00, 0x41, 0x22, 0x14, 0x08],
+    [0x02, 0x01, 0x59, 0x09, 0x06],
+    [0x3E, 0x41, 0x5D, 0x59, 0x4E],
+    [0x7C, 0x12, 0x11, 0x12, 0x7C],
+    [0x7F, 0x49, 0x49, 0x49, 0x36],
+    [0x3E, 0x41, 0x41, 0x41, 0x22],
+    [0x7F, 0x41, 0x41, 0x41, 0x3E],
+    [0x7F, 0x49, 0x49, 0x49, 0x41],
+    [0x7F, 0x09, 0x09, 0x09, 0x01],
+    [0x3E, 0x41, 0x41, 0x51, 0x73],
+    [0x7F, 0x08, 0x08, 0x08, 0x7F],
+    [0x00, 0x41, 0x7F, 0x41, 0x00],
+    [0x20, 0x40, 0x41, 0x3F, 0x01],
+    [0x7F, 0x08, 0x14, 0x22, 0x41],
+    [0x7F, 0x40, 0x40, 0x40, 0x40],
+    [0x7F, 0x02, 0x1C, 0x02, 0x7F],
+    [0x7F, 0x04, 0x08, 0x10, 0x7F],
+    [0x3E, 0x41, 0x41, 0x41, 0x3E],
+    [0x7F, 0x09, 0x09, 0x09, 0x06],
+    [0x3E, 0x41, 0x51, 0x21, 0x5E],
+    [0x7F, 0x09, 0x19, 0x29, 0x46],
+    [0x26, 0x49, 0x49, 0x49, 0x32],
+    [0x03, 0x01, 0x7F, 0x01, 0x03],
+    [0x3F, 0x40, 0x40, 0x40, 0x3F],
+    [0x1F, 0x20, 0x40, 0x20, 0x1F],
+    [0x3F, 0x40, 0x38, 0x40, 0x3F],
+    [0x63, 0x14, 0x08, 0x14, 0x63],
+    [0x03, 0x04, 0x78, 0x04, 0x03],
+    [0x61, 0x59, 0x49, 0x4D, 0x43],
+    [0x00, 0x7F, 0x41, 0x41, 0x41],
+    [0x02, 0x04, 0x08, 0x10, 0x20],
+    [0x00, 0x41, 0x41, 0x41, 0x7F],
+    [0x04, 0x02, 0x01, 0x02, 0x04],
+    [0x40, 0x40, 0x40, 0x40, 0x40],
+    [0x00, 0x03, 0x07, 0x08, 0x00],
+    [0x20, 0x54, 0x54, 0x78, 0x40],
+    [0x7F, 0x28, 0x44, 0x44, 0x38],
+    [0x38, 0x44, 0x44, 0x44, 0x28],
+    [0x38, 0x44, 0x44, 0x28, 0x7F],
+    [0x38, 0x54, 0x54, 0x54, 0x18],
+    [0x00, 0x08, 0x7E, 0x09, 0x02],
+    [0x18, 0xA4, 0xA4, 0x9C, 0x78],
+    [0x7F, 0x08, 0x04, 0x04, 0x78],
+    [0x00, 0x44, 0x7D, 0x40, 0x00],
+    [0x20, 0x40, 0x40, 0x3D, 0x00],
+    [0x7F, 0x10, 0x28, 0x44, 0x00],
+    [0x00, 0x41, 0x7F, 0x40, 0x00],
+    [0x7C, 0x04, 0x78, 0x04, 0x78],
+    [0x7C, 0x08, 0x04, 0x04, 0x78],
+    [0x38, 0x44, 0x44, 0x44, 0x38],
+    [0xFC, 0x18, 0x24, 0x24, 0x18],
+    [0x18, 0x24, 0x24, 0x18, 0xFC],
+    [0x7C, 0x08, 0x04, 0x04, 0x08],
+    [0x48, 0x54, 0x54, 0x54, 0x24],
+    [0x04, 0x04, 0x3F, 0x44, 0x24],
+    [0x3C, 0x40, 0x40, 0x20, 0x7C],
+    [0x1C, 0x20, 0x40, 0x20, 0x1C],
+    [0x3C, 0x40, 0x30, 0x40, 0x3C],
+    [0x44, 0x28, 0x10, 0x28, 0x44],
+    [0x4C, 0x90, 0x90, 0x90, 0x7C],
+    [0x44, 0x64, 0x54, 0x4C, 0x44],
+    [0x00, 0x08, 0x36, 0x41, 0x00],
+    [0x00, 0x00, 0x77, 0x00, 0x00],
+    [0x00, 0x41, 0x36, 0x08, 0x00],
+    [0x02, 0x01, 0x02, 0x04, 0x02],
+];
+
+// Micro sign is the only non-ASCII letter of the SI prefixes
+pub const FONT_5X8_MICRO: [u8; 5] = [0xFC, 0x40, 0x40, 0x20, 0x7C];
+
+// Ohm of the base units, for both the Greek capital omega and the ohm sign
+pub const FONT_5X8_OMEGA: [u8; 5] = [0x4E, 0x71, 0x01, 0x71, 0x4E];
+
+// Names of the rules a value can break, shown by tooltips and coded by the C ABI
+pub const RULE_ABOVE_HI_LIMIT: &str = "Above HI_LIMIT";
+
+pub const RULE_BELOW_LO_LIMIT: &str = "Below LO_LIMIT";
+
+pub const RULE_OUTLIER: &str = "Outlier";
+
+pub const RULE_EWMA: &str = "EWMA out of control";
+
+pub const RULE_CUSUM: &str = "CUSUM out of control";
+
+// CSV import reads one column per site unless the format is "long"
+pub const DEFAULT_CSV_FORMAT: &str = "wide";
+
+pub const DEFAULT_CSV_KEY_COLUMNS: [&str; 1] = ["SITE_NUM"];
+
+pub const DEFAULT_CSV_VALUE_COLUMN: &str = "VALUE";
+
+// Labels of the header rows and columns holding limits and units
+pub const CSV_INFO_LABELS: [&str; 3] = ["HI_LIMIT", "LO_LIMIT", "UNITS"];
@@ -0,0 +1,169 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Estimate process sigma from the average moving range of successive values
+ * @method movingRangeSigma
+ * @param {array} values
+ * @returns {number} - NaN when values has less than 2 items
+ */
+pub fn movingRangeSigma(values: &[f64]) -> f64 {
+    let ranges: Vec<f64> = values.windows(2).map(|w| (w[1] - w[0]).abs()).collect();
+
+    mean(&ranges) / MOVING_RANGE_D2
+}
+
+/**
+ * Get first values of the baseline, the whole values when the baseline has less than 2 of them
+ * @method baselineValues
+ * @param {array} values
+ * @param {number} baseline - number of first values
+ * @returns {array} - at least 2 values when values has them, sigma needs a moving range
+ */
+pub fn baselineValues(values: &[f64], baseline: usize) -> &[f64] {
+    if baseline.min(values.len()) < 2 {
+        return values;
+    }
+
+    &values[..baseline.min(values.len())]
+}
+
+/**
+ * Get EWMA statistic and time-varying control limits of values
+ * @method ewmaChart
+ * @param {array} values
+ * @param {number} lambda - smoothing weight in (0, 1]
+ * @param {number} l - width of control limits in sigma
+ * @param {number} baseline - number of first values used to estimate center and sigma,
+ *                            a baseline below 2 values uses the whole values
+ * @returns {object}
+ */
+pub fn ewmaChart(values: &[f64], lambda: f64, l: f64, baseline: usize) -> EwmaStruct {
+    let reference = baselineValues(values, baseline);
+    let center = mean(reference);
+    let sigma = movingRangeSigma(reference);
+
+    let mut statistic: Vec<f64> = Vec::new();
+    let mut lowerLimit: Vec<f64> = Vec::new();
+    let mut upperLimit: Vec<f64> = Vec::new();
+    let mut firstOutOfControl: Option<u32> = None;
+    let mut z = center;
+
+    for (i, value) in values.iter().enumerate() {
+        z = lambda * value + (1.0 - lambda) * z;
+
+        // Limits widen towards the asymptotic value as weights accumulate
+        let width = l
+            * sigma
+            * (lambda / (2.0 - lambda) * (1.0 - (1.0 - lambda).powi(2 * (i as i32 + 1)))).sqrt();
+
+        if firstOutOfControl.is_none() && (z < center - width || z > center + width) {
+            firstOutOfControl = Some(i as u32);
+        }
+
+        statistic.push(z);
+        lowerLimit.push(center - width);
+        upperLimit.push(center + width);
+    }
+
+    EwmaStruct {
+        x: (0..values.len()).map(|i| i as f64).collect(),
+        statistic,
+        center,
+        lowerLimit,
+        upperLimit,
+        firstOutOfControl,
+    }
+}
+
+/**
+ * Get tabular CUSUM statistics of values
+ * @method cusumChart
+ * @param {array} values
+ * @param {number} k - allowance in sigma
+ * @param {number} h - decision interval in sigma
+ * @param {number} baseline - number of first values used to estimate center and sigma,
+ *                            a baseline below 2 values uses the whole values
+ * @returns {object}
+ */
+pub fn cusumChart(values: &[f64], k: f64, h: f64, baseline: usize) -> CusumStruct {
+    let reference = baselineValues(values, baseline);
+    let center = mean(reference);
+    let sigma = movingRangeSigma(reference);
+    let limit = h * sigma;
+
+    let mut upperStatistic: Vec<f64> = Vec::new();
+    let mut lowerStatistic: Vec<f64> = Vec::new();
+    let mut firstOutOfControl: Option<u32> = None;
+    let mut upper: f64 = 0.0;
+    let mut lower: f64 = 0.0;
+
+    for (i, value) in values.iter().enumerate() {
+        upper = (upper + value - (center + k * sigma)).max(0.0);
+        lower = (lower + (center - k * sigma) - value).max(0.0);
+
+        if firstOutOfControl.is_none() && (upper > limit || lower > limit) {
+            firstOutOfControl = Some(i as u32);
+        }
+
+        upperStatistic.push(upper);
+        lowerStatistic.push(lower);
+    }
+
+    CusumStruct {
+        x: (0..values.len()).map(|i| i as f64).collect(),
+        upperStatistic,
+        lowerStatistic,
+        limit: vec![limit; values.len()],
+        firstOutOfControl,
+    }
+}
+
+/**
+ * Get EWMA and CUSUM charts of every group
+ * @method groupsControlCharts
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @returns {array} - [ewma, cusum]
+ */
+pub fn groupsControlCharts(
+    groups: Vec<GroupItemStruct>,
+    config: &ConfigStruct,
+) -> (Vec<EwmaStruct>, Vec<CusumStruct>) {
+    if !config.controlCharts.unwrap_or(false) {
+        return (vec![], vec![]);
+    }
+
+    let lambda = config.ewmaLambda.unwrap_or(DEFAULT_EWMA_LAMBDA);
+    let l = config.ewmaL.unwrap_or(DEFAULT_EWMA_L);
+    let k = config.cusumK.unwrap_or(DEFAULT_CUSUM_K);
+    let h = config.cusumH.unwrap_or(DEFAULT_CUSUM_H);
+
+    groups
+        .into_iter()
+        .map(|group| {
+            let xValues = groupXValues(&group);
+            let values = group.values.unwrap_or(vec![]);
+            let baseline = config
+                .controlBaseline
+                .map(|count| count as usize)
+                .unwrap_or(values.len());
+
+            (
+                EwmaStruct {
+                    x: xValues.clone(),
+                    ..ewmaChart(&values, lambda, l, baseline)
+                },
+                CusumStruct {
+                    x: xValues,
+                    ..cusumChart(&values, k, h, baseline)
+                },
+            )
+        })
+        .unzip()
+}
@@ -0,0 +1,509 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Guess delimiter from the first line, tab and semicolon win over comma
+ * @method detectDelimiter
+ * @param {string} text
+ * @returns {string} - comma when the line has no delimiter
+ */
+pub fn detectDelimiter(text: &str) -> char {
+    let mut counts = [('\t', 0), (';', 0), (',', 0)];
+    let mut quoted = false;
+
+    for c in text.chars() {
+        match c {
+            '"' => quoted = !quoted,
+            '\n' if !quoted => break,
+            _ if !quoted => {
+                for (delimiter, count) in counts.iter_mut() {
+                    if *delimiter == c {
+                        *count += 1;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    counts
+        .iter()
+        .find(|(_, count)| *count > 0)
+        .map(|(delimiter, _)| *delimiter)
+        .unwrap_or(',')
+}
+
+/**
+ * Split CSV text into rows of fields, quoted fields may hold delimiters, newlines and "" for a quote
+ * @method parseRows
+ * @param {string} text
+ * @param {string} delimiter
+ * @returns {array} - blank rows left out, error when a quote isn't closed
+ */
+pub fn parseRows(text: &str, delimiter: char) -> Result<Vec<Vec<String>>, String> {
+    // Spreadsheet exports often start with a byte order mark
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut row: Vec<String> = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut line = 1;
+    let mut quoteLine = 1;
+    let mut chars = text.chars().peekable();
+
+    let mut endRow = |row: &mut Vec<String>| {
+        if row.iter().any(|field| !field.trim().is_empty()) {
+            rows.push(std::mem::take(row));
+        }
+
+        row.clear();
+    };
+
+    while let Some(c) = chars.next() {
+        if quoted {
+            if c == '"' {
+                if chars.peek() == Some(&'"') {
+                    field.push('"');
+                    chars.next();
+                } else {
+                    quoted = false;
+                }
+            } else {
+                if c == '\n' {
+                    line += 1;
+                }
+
+                field.push(c);
+            }
+
+            continue;
+        }
+
+        match c {
+            '"' if field.trim().is_empty() => {
+                field.clear();
+                quoted = true;
+                quoteLine = line;
+            }
+            c if c == delimiter => row.push(std::mem::take(&mut field)),
+            '\r' => {}
+            '\n' => {
+                line += 1;
+                row.push(std::mem::take(&mut field));
+                endRow(&mut row);
+            }
+            _ => field.push(c),
+        }
+    }
+
+    if quoted {
+        return Err(format!("Unclosed quote in CSV line {}", quoteLine));
+    }
+
+    if !field.is_empty() || !row.is_empty() {
+        row.push(field);
+        endRow(&mut row);
+    }
+
+    Ok(rows)
+}
+
+/**
+ * Parse number of a CSV field like "1.25", "1,25" or "−3"
+ * @method parseCsvNumber
+ * @param {string} text
+ * @param {boolean} decimalComma - comma is the decimal separator
+ * @returns {number} - null when text isn't a number
+ */
+pub fn parseCsvNumber(text: &str, decimalComma: bool) -> Option<f64> {
+    let text = text.trim().replace('\u{2212}', "-");
+
+    if decimalComma {
+        text.replace(',', ".").parse().ok()
+    } else {
+        text.parse().ok()
+    }
+}
+
+/**
+ * Get site number from the trailing digits of a name like "Site 3" or "S12"
+ * @method siteNumber
+ * @param {string} name
+ * @returns {number} - null when name doesn't end with digits
+ */
+pub fn siteNumber(name: &str) -> Option<u32> {
+    let name = name.trim();
+    let start = name.trim_end_matches(|c: char| c.is_ascii_digit()).len();
+
+    name[start..].parse().ok()
+}
+
+/**
+ * Give every group a site, groups without a parsed site take the lowest free numbers in order
+ * @method assignSites
+ * @param {array} groups - groups with the parsed site or null in keyValues
+ * @param {array} names - name of every group for errors
+ * @returns {array} - error when two groups have the same site, they couldn't be told apart
+ */
+fn assignSites(
+    mut groups: Vec<GroupItemStruct>,
+    names: &[String],
+) -> Result<Vec<GroupItemStruct>, String> {
+    let mut used: Vec<(u32, &str)> = Vec::new();
+
+    for (group, name) in groups.iter().zip(names.iter()) {
+        if let Some(site) = group.keyValues.and_then(|keyValues| keyValues.SITE_NUM) {
+            if let Some((_, other)) = used.iter().find(|(other, _)| *other == site) {
+                return Err(format!(
+                    "Duplicate SITE_NUM {} in CSV: {} and {}",
+                    site, other, name
+                ));
+            }
+
+            used.push((site, name));
+        }
+    }
+
+    let mut next = 1;
+
+    for group in groups.iter_mut() {
+        if group
+            .keyValues
+            .is_some_and(|keyValues| keyValues.SITE_NUM.is_some())
+        {
+            continue;
+        }
+
+        while used.iter().any(|(site, _)| *site == next) {
+            next += 1;
+        }
+
+        group.keyValues = Some(KeyValuesStruct {
+            SITE_NUM: Some(next),
+        });
+        next += 1;
+    }
+
+    Ok(groups)
+}
+
+/**
+ * Get index of a column by name, case and surrounding spaces ignored
+ * @method columnIndex
+ * @param {array} header
+ * @param {string} name
+ * @returns {number} - error when header has no such column
+ */
+fn columnIndex(header: &[String], name: &str) -> Result<usize, String> {
+    header
+        .iter()
+        .position(|column| column.trim().eq_ignore_ascii_case(name.trim()))
+        .ok_or(format!("CSV has no column {}", name))
+}
+
+/**
+ * Get trimmed field of a row, short rows give empty fields
+ * @method cell
+ * @param {array} row
+ * @param {number} index
+ * @returns {string}
+ */
+fn cell(row: &[String], index: usize) -> &str {
+    row.get(index).map(|field| field.trim()).unwrap_or("")
+}
+
+/**
+ * Get timestamp of a field, plain numbers are epoch milliseconds
+ * @method parseCsvTimestamp
+ * @param {string} text
+ * @returns {object}
+ */
+fn parseCsvTimestamp(text: &str) -> TimestampEnum {
+    match text.parse::<f64>() {
+        Ok(epoch) => TimestampEnum::Epoch(epoch),
+        Err(_) => TimestampEnum::Iso(text.to_string()),
+    }
+}
+
+/**
+ * Set limit or units of info from a labelled field, filled fields are kept
+ * @method fillInfo
+ * @param {object} info
+ * @param {string} label - HI_LIMIT, LO_LIMIT or UNITS
+ * @param {string} text
+ * @param {boolean} decimalComma
+ * @returns {object} - error when a limit isn't a number
+ */
+fn fillInfo(
+    info: &mut InfoStruct,
+    label: &str,
+    text: &str,
+    decimalComma: bool,
+) -> Result<(), String> {
+    if text.is_empty() {
+        return Ok(());
+    }
+
+    let limit =
+        || parseCsvNumber(text, decimalComma).ok_or(format!("Invalid {} in CSV: {}", label, text));
+
+    match label {
+        "HI_LIMIT" if info.HI_LIMIT.is_none() => info.HI_LIMIT = Some(limit()?),
+        "LO_LIMIT" if info.LO_LIMIT.is_none() => info.LO_LIMIT = Some(limit()?),
+        "UNITS" if info.UNITS.is_none() => info.UNITS = Some(text.to_string()),
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/**
+ * Read wide CSV, every named column except the timestamp column is a site
+ * @method readWide
+ * @param {array} header
+ * @param {array} rows - data rows
+ * @param {object} config
+ * @param {boolean} decimalComma
+ * @returns {array} - groups in column order
+ */
+fn readWide(
+    header: &[String],
+    rows: &[Vec<String>],
+    config: &ConfigStruct,
+    decimalComma: bool,
+) -> Result<Vec<GroupItemStruct>, String> {
+    let timestampIndex = config
+        .csvTimestampColumn
+        .as_deref()
+        .map(|name| columnIndex(header, name))
+        .transpose()?;
+
+    let mut groups: Vec<GroupItemStruct> = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+
+    for (column, name) in header.iter().enumerate() {
+        // Unnamed column holds the labels of the header rows
+        if Some(column) == timestampIndex || name.trim().is_empty() {
+            continue;
+        }
+
+        let mut values: Vec<f64> = Vec::new();
+        let mut timestamps: Vec<TimestampEnum> = Vec::new();
+
+        for (rowIndex, row) in rows.iter().enumerate() {
+            let text = cell(row, column);
+
+            if text.is_empty() {
+                continue;
+            }
+
+            values.push(parseCsvNumber(text, decimalComma).ok_or(format!(
+                "Invalid value in CSV row {}, column {}: {}",
+                rowIndex + 1,
+                name.trim(),
+                text
+            ))?);
+
+            if let Some(index) = timestampIndex {
+                timestamps.push(parseCsvTimestamp(cell(row, index)));
+            }
+        }
+
+        names.push(format!("column {}", name.trim()));
+        groups.push(GroupItemStruct {
+            keyValues: Some(KeyValuesStruct {
+                SITE_NUM: siteNumber(name),
+            }),
+            color: None,
+            stats: None,
+            values: Some(values),
+            timestamps: timestampIndex.map(|_| timestamps),
+        });
+    }
+
+    assignSites(groups, &names)
+}
+
+/**
+ * Read long CSV, rows are grouped by the key columns and limit and units columns fill info
+ * @method readLong
+ * @param {array} header
+ * @param {array} rows - data rows
+ * @param {object} config
+ * @param {boolean} decimalComma
+ * @param {object} info
+ * @returns {array} - groups in order of the first row of each key,
+ *                    error when keys of the same SITE_NUM make several groups
+ */
+fn readLong(
+    header: &[String],
+    rows: &[Vec<String>],
+    config: &ConfigStruct,
+    decimalComma: bool,
+    info: &mut InfoStruct,
+) -> Result<Vec<GroupItemStruct>, String> {
+    let keyColumns: Vec<String> = config.csvKeyColumns.clone().unwrap_or(
+        DEFAULT_CSV_KEY_COLUMNS
+            .iter()
+            .map(|name| name.to_string())
+            .collect(),
+    );
+    let keyIndexes = keyColumns
+        .iter()
+        .map(|name| columnIndex(header, name))
+        .collect::<Result<Vec<usize>, String>>()?;
+    let valueIndex = columnIndex(
+        header,
+        config
+            .csvValueColumn
+            .as_deref()
+            .unwrap_or(DEFAULT_CSV_VALUE_COLUMN),
+    )?;
+    let timestampIndex = config
+        .csvTimestampColumn
+        .as_deref()
+        .map(|name| columnIndex(header, name))
+        .transpose()?;
+
+    // Site of a group comes from the SITE_NUM key, else groups are numbered in order
+    let siteIndex = keyColumns
+        .iter()
+        .position(|name| name.trim().eq_ignore_ascii_case("SITE_NUM"))
+        .map(|position| keyIndexes[position]);
+    let infoIndexes: Vec<(&str, usize)> = CSV_INFO_LABELS
+        .iter()
+        .filter_map(|label| Some((*label, columnIndex(header, label).ok()?)))
+        .collect();
+
+    let mut keys: Vec<Vec<&str>> = Vec::new();
+    let mut groups: Vec<GroupItemStruct> = Vec::new();
+
+    for (rowIndex, row) in rows.iter().enumerate() {
+        for (label, index) in infoIndexes.iter() {
+            fillInfo(info, label, cell(row, *index), decimalComma)?;
+        }
+
+        let text = cell(row, valueIndex);
+
+        if text.is_empty() {
+            continue;
+        }
+
+        let value = parseCsvNumber(text, decimalComma).ok_or(format!(
+            "Invalid value in CSV row {}, column {}: {}",
+            rowIndex + 1,
+            header[valueIndex].trim(),
+            text
+        ))?;
+
+        let key: Vec<&str> = keyIndexes.iter().map(|index| cell(row, *index)).collect();
+
+        let position = match keys.iter().position(|other| *other == key) {
+            Some(position) => position,
+            None => {
+                let site = siteIndex.and_then(|index| siteNumber(cell(row, index)));
+
+                keys.push(key);
+                groups.push(GroupItemStruct {
+                    keyValues: Some(KeyValuesStruct { SITE_NUM: site }),
+                    color: None,
+                    stats: None,
+                    values: Some(vec![]),
+                    timestamps: timestampIndex.map(|_| vec![]),
+                });
+
+                groups.len() - 1
+            }
+        };
+
+        let group = &mut groups[position];
+
+        if let Some(values) = group.values.as_mut() {
+            values.push(value);
+        }
+
+        if let (Some(timestamps), Some(index)) = (group.timestamps.as_mut(), timestampIndex) {
+            timestamps.push(parseCsvTimestamp(cell(row, index)));
+        }
+    }
+
+    let names: Vec<String> = keys
+        .iter()
+        .map(|key| format!("key {}", key.join("/")))
+        .collect();
+
+    assignSites(groups, &names)
+}
+
+/**
+ * Read CSV or TSV text into data, wide with one column per site or long with key and value columns,
+ * limits and units come from config, from rows labelled HI_LIMIT, LO_LIMIT and UNITS or from long columns
+ * @method readCsv
+ * @param {string} text
+ * @param {object} config - csvFormat, csvDelimiter, csvDecimalComma, csvKeyColumns, csvValueColumn,
+ *                          csvTimestampColumn, csvHiLimit, csvLoLimit and csvUnits
+ * @returns {object} - error when a column is missing or a value isn't a number
+ */
+pub fn readCsv(text: &str, config: &ConfigStruct) -> Result<DataStruct, String> {
+    let delimiter = match config.csvDelimiter.as_deref() {
+        None => detectDelimiter(text),
+        Some("\\t") | Some("tab") => '\t',
+        Some(delimiter) if delimiter.chars().count() == 1 => delimiter.chars().next().unwrap(),
+        Some(delimiter) => return Err(format!("Invalid csvDelimiter: {}", delimiter)),
+    };
+
+    // Comma can't be the decimal separator of comma separated values
+    let decimalComma = config.csvDecimalComma.unwrap_or(delimiter != ',');
+
+    let mut info = InfoStruct {
+        HI_LIMIT: None,
+        LO_LIMIT: None,
+        UNITS: None,
+    };
+
+    // Header rows may come before or after the column names, the first other row names the columns
+    let mut header: Option<Vec<String>> = None;
+    let mut rows: Vec<Vec<String>> = Vec::new();
+
+    for row in parseRows(text, delimiter)? {
+        let label = row[0].trim().to_ascii_uppercase();
+
+        if CSV_INFO_LABELS.contains(&label.as_str()) {
+            let text = row[1..]
+                .iter()
+                .map(|field| field.trim())
+                .find(|field| !field.is_empty())
+                .unwrap_or("");
+
+            fillInfo(&mut info, &label, text, decimalComma)?;
+        } else if header.is_none() {
+            header = Some(row);
+        } else {
+            rows.push(row);
+        }
+    }
+
+    let header = header.ok_or("CSV has no header row")?;
+
+    let groups = match config.csvFormat.as_deref().unwrap_or(DEFAULT_CSV_FORMAT) {
+        "wide" => readWide(&header, &rows, config, decimalComma)?,
+        "long" => readLong(&header, &rows, config, decimalComma, &mut info)?,
+        format => return Err(format!("Unknown csvFormat: {}", format)),
+    };
+
+    Ok(DataStruct {
+        groups: Some(groups),
+        stats: None,
+        info: Some(InfoStruct {
+            HI_LIMIT: config.csvHiLimit.or(info.HI_LIMIT),
+            LO_LIMIT: config.csvLoLimit.or(info.LO_LIMIT),
+            UNITS: config.csvUnits.clone().or(info.UNITS),
+        }),
+    })
+}
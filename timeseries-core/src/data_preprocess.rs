@@ -2,7 +2,7 @@
 
 /* -------------- Import Modules -------------- */
 
-use crate::{consts::*, structs::*};
+use crate::{consts::*, structs::*, timestamps::*};
 
 /* -------------- Define functions -------------- */
 
@@ -11,7 +11,7 @@ use crate::{consts::*, structs::*};
  * @method filterGroup
  * @param {array} groups
  * @param {array} hiddenGroups
- * @returns {array}
+ * @returns {array} - NaN values and values with an invalid timestamp left out
  */
 pub fn filterGroup(groups: Vec<GroupItemStruct>, hiddenGroups: Vec<u32>) -> Vec<GroupItemStruct> {
     // Define filtered groups
@@ -36,23 +36,39 @@ pub fn filterGroup(groups: Vec<GroupItemStruct>, hiddenGroups: Vec<u32>) -> Vec<
         // Get values
         let values = item.values.unwrap_or(vec![]);
 
-        // Define list of values
+        // Timestamps are only kept when there is one per value
+        let timestamps = item
+            .timestamps
+            .filter(|timestamps| timestamps.len() == values.len());
+
+        // Define list of values and their timestamps
         let mut listValues: Vec<f64> = Vec::new();
+        let mut listTimestamps: Vec<TimestampEnum> = Vec::new();
 
         // Loop through values
-        for value in values.iter() {
+        for (i, value) in values.iter().enumerate() {
+            // A value without a valid timestamp can't be placed on the time axis
+            let validTimestamp = timestamps
+                .as_ref()
+                .is_none_or(|timestamps| timestampToEpoch(&timestamps[i]).is_some());
+
             // Check if value is !NaN
-            if !value.is_nan() {
-                listValues.push(*value)
+            if !value.is_nan() && validTimestamp {
+                listValues.push(*value);
+
+                if let Some(timestamps) = &timestamps {
+                    listTimestamps.push(timestamps[i].clone());
+                }
             }
         }
 
         // Push item to returnGroups
         returnGroups.push(GroupItemStruct {
             color: item.color.clone(),
-            keyValues: item.keyValues.clone(),
+            keyValues: item.keyValues,
             stats: item.stats.clone(),
             values: Some(listValues),
+            timestamps: timestamps.map(|_| listTimestamps),
         })
     }
 
@@ -60,7 +76,7 @@ pub fn filterGroup(groups: Vec<GroupItemStruct>, hiddenGroups: Vec<u32>) -> Vec<
 }
 
 /**
- * Preprocess data
+ * Preprocess data, x is the value index or its timestamp in epoch milliseconds
  *
  * @method preprocess
  * @param {array} groups
@@ -72,6 +88,9 @@ pub fn preprocess(groups: Vec<GroupItemStruct>) -> Vec<PreprocessItemStruct> {
 
     // Loop through groups
     for item in groups.into_iter() {
+        // Get x of values, timestamps when the group has them
+        let xValues = groupXValues(&item);
+
         // Get values
         let values = item.values.unwrap_or(vec![]);
 
@@ -80,10 +99,7 @@ pub fn preprocess(groups: Vec<GroupItemStruct>) -> Vec<PreprocessItemStruct> {
             // Push item to returnedGroups
             returnedGroups.push(PreprocessItemStruct {
                 color: item.color.clone().unwrap_or(DEFAULT_COLOR.to_string()),
-                keyValues: item
-                    .keyValues
-                    .clone()
-                    .unwrap_or(KeyValuesStruct { SITE_NUM: None }),
+                keyValues: item.keyValues.unwrap_or(KeyValuesStruct { SITE_NUM: None }),
                 stats: item.stats.clone().unwrap_or(StatsGroupStruct {
                     Count: None,
                     Cp: None,
@@ -100,8 +116,9 @@ pub fn preprocess(groups: Vec<GroupItemStruct>) -> Vec<PreprocessItemStruct> {
                     std: None,
                 }),
                 value: el,
-                x: i as u32,
+                x: xValues[i],
                 y: el,
+                outlier: false,
             })
         }
     }
@@ -143,9 +160,9 @@ pub fn groupsValuesList(groups: Vec<GroupItemStruct>) -> Vec<Vec<f64>> {
                     let mut list: Vec<f64> = Vec::new();
 
                     // Check if elNextValues is exist
-                    if !elNextValues.is_none() {
+                    if let Some(elNextValue) = elNextValues {
                         list.push(*el);
-                        list.push(*elNextValues.unwrap());
+                        list.push(*elNextValue);
                     } else {
                         list.push(*el);
                     }
@@ -159,8 +176,8 @@ pub fn groupsValuesList(groups: Vec<GroupItemStruct>) -> Vec<Vec<f64>> {
                     let mut list: Vec<f64> = Vec::new();
 
                     // Check if item is exist
-                    if !item.is_none() {
-                        list.push(*item.unwrap());
+                    if let Some(item) = item {
+                        list.push(*item);
                         list.push(*el);
                     } else {
                         list.push(*el);
@@ -178,9 +195,7 @@ pub fn groupsValuesList(groups: Vec<GroupItemStruct>) -> Vec<Vec<f64>> {
 
             // Loop through values
             for value in values {
-                let mut list: Vec<f64> = Vec::new();
-
-                list.push(value);
+                let list: Vec<f64> = vec![value];
 
                 returnedValues.push(list);
             }
@@ -0,0 +1,224 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get smallest odd number not below value
+ * @method nextOdd
+ * @param {number} value
+ * @returns {number}
+ */
+fn nextOdd(value: usize) -> usize {
+    if value.is_multiple_of(2) {
+        value + 1
+    } else {
+        value
+    }
+}
+
+/**
+ * Local linear fit at a position on the index axis, tricube weights on the span nearest values
+ * @method loessAt
+ * @param {array} values
+ * @param {array} weights - robustness weight of every value
+ * @param {number} span - number of neighbours, may exceed the number of values
+ * @param {number} at - position to evaluate, may lie outside the values
+ * @returns {number}
+ */
+fn loessAt(values: &[f64], weights: &[f64], span: usize, at: f64) -> f64 {
+    let n = values.len();
+    let size = span.min(n);
+
+    let centre = at.round().clamp(0.0, (n - 1) as f64) as usize;
+    let start = centre.saturating_sub(size / 2).min(n - size);
+    let end = start + size;
+
+    // A span larger than the values widens the neighbourhood proportionally
+    let maxDistance = (start..end)
+        .map(|j| (j as f64 - at).abs())
+        .fold(1.0, f64::max)
+        * (span.max(n) as f64 / n as f64)
+        * 1.0001;
+
+    let x: Vec<f64> = (start..end).map(|j| j as f64 - at).collect();
+    let fitWeights: Vec<f64> = x
+        .iter()
+        .zip(weights[start..end].iter())
+        .map(|(d, w)| w * (1.0 - (d.abs() / maxDistance).powi(3)).powi(3))
+        .collect();
+
+    let totalWeight: f64 = fitWeights.iter().sum();
+
+    if totalWeight <= 0.0 {
+        return mean(&values[start..end]);
+    }
+
+    polynomialFit(&x, &values[start..end], &fitWeights, 1.min(size - 1))
+        .map(|coefficients| coefficients[0])
+        .unwrap_or(
+            values[start..end]
+                .iter()
+                .zip(fitWeights.iter())
+                .map(|(v, w)| v * w)
+                .sum::<f64>()
+                / totalWeight,
+        )
+}
+
+/**
+ * Trailing moving average, output is window - 1 values shorter
+ * @method movingAverage
+ * @param {array} values
+ * @param {number} window
+ * @returns {array}
+ */
+fn movingAverage(values: &[f64], window: usize) -> Vec<f64> {
+    values
+        .windows(window)
+        .map(|w| w.iter().sum::<f64>() / window as f64)
+        .collect()
+}
+
+/**
+ * Seasonal-trend decomposition by LOESS (Cleveland et al. 1990)
+ * @method stlDecomposition
+ * @param {array} values - at least two periods
+ * @param {number} period - number of values per cycle, at least 2
+ * @param {number} seasonalWindow - LOESS span of the cycle-subseries
+ * @param {number} robustIterations - outer loops downweighting large residuals
+ * @returns {array} - [trend, seasonal, residual]
+ */
+pub fn stlDecomposition(
+    values: &[f64],
+    period: usize,
+    seasonalWindow: usize,
+    robustIterations: usize,
+) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let n = values.len();
+    let seasonalSpan = nextOdd(seasonalWindow.max(7));
+    let trendSpan =
+        nextOdd((1.5 * period as f64 / (1.0 - 1.5 / seasonalSpan as f64)).ceil() as usize);
+    let lowPassSpan = nextOdd(period);
+
+    let mut trend = vec![0.0; n];
+    let mut seasonal = vec![0.0; n];
+    let mut robustness = vec![1.0; n];
+
+    for outer in 0..=robustIterations {
+        for _ in 0..STL_INNER_ITERATIONS {
+            let detrended: Vec<f64> = values
+                .iter()
+                .zip(trend.iter())
+                .map(|(y, t)| y - t)
+                .collect();
+
+            // Smooth every cycle-subseries, extended by one cycle at both ends
+            let mut cycle = vec![0.0; n + 2 * period];
+
+            for k in 0..period.min(n) {
+                let indices: Vec<usize> = (k..n).step_by(period).collect();
+                let subseries: Vec<f64> = indices.iter().map(|i| detrended[*i]).collect();
+                let subWeights: Vec<f64> = indices.iter().map(|i| robustness[*i]).collect();
+
+                for j in 0..subseries.len() + 2 {
+                    cycle[k + j * period] =
+                        loessAt(&subseries, &subWeights, seasonalSpan, j as f64 - 1.0);
+                }
+            }
+
+            // Low-pass filter removes the trend leaking into the cycle-subseries
+            let filtered = movingAverage(&movingAverage(&movingAverage(&cycle, period), period), 3);
+            let ones = vec![1.0; filtered.len()];
+            let lowPass: Vec<f64> = (0..n)
+                .map(|i| loessAt(&filtered, &ones, lowPassSpan, i as f64))
+                .collect();
+
+            seasonal = (0..n).map(|i| cycle[period + i] - lowPass[i]).collect();
+
+            let deseasonalized: Vec<f64> = values
+                .iter()
+                .zip(seasonal.iter())
+                .map(|(y, s)| y - s)
+                .collect();
+
+            trend = (0..n)
+                .map(|i| loessAt(&deseasonalized, &robustness, trendSpan, i as f64))
+                .collect();
+        }
+
+        if outer == robustIterations {
+            break;
+        }
+
+        // Bisquare weights of the residuals
+        let absResiduals: Vec<f64> = (0..n)
+            .map(|i| (values[i] - trend[i] - seasonal[i]).abs())
+            .collect();
+        let scale = 6.0 * median(&sortedValues(&absResiduals));
+
+        robustness = absResiduals
+            .iter()
+            .map(|r| {
+                if scale == 0.0 {
+                    1.0
+                } else {
+                    (1.0 - (r / scale).powi(2)).max(0.0).powi(2)
+                }
+            })
+            .collect();
+    }
+
+    let residual = (0..n).map(|i| values[i] - trend[i] - seasonal[i]).collect();
+
+    (trend, seasonal, residual)
+}
+
+/**
+ * Get seasonal-trend decomposition of every group
+ * @method groupsDecomposition
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @returns {array} - empty when stlPeriod is not set, null for groups shorter than two periods
+ */
+pub fn groupsDecomposition(
+    groups: Vec<GroupItemStruct>,
+    config: &ConfigStruct,
+) -> Vec<Option<DecompositionStruct>> {
+    let period = match config.stlPeriod {
+        Some(period) => period as usize,
+        None => return vec![],
+    };
+    let seasonalWindow = config
+        .stlSeasonalWindow
+        .unwrap_or(DEFAULT_STL_SEASONAL_WINDOW) as usize;
+    let robustIterations = config
+        .stlRobustIterations
+        .unwrap_or(DEFAULT_STL_ROBUST_ITERATIONS) as usize;
+
+    groups
+        .into_iter()
+        .map(|group| {
+            let xValues = groupXValues(&group);
+            let values = group.values.unwrap_or(vec![]);
+
+            if period < 2 || values.len() < 2 * period {
+                return None;
+            }
+
+            let (trend, seasonal, residual) =
+                stlDecomposition(&values, period, seasonalWindow, robustIterations);
+
+            Some(DecompositionStruct {
+                period: period as u32,
+                x: xValues,
+                trend,
+                seasonal,
+                residual,
+            })
+        })
+        .collect()
+}
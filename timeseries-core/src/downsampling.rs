@@ -0,0 +1,114 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::structs::*;
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Pick indexes of points to draw, min and max of y per bucket of equal x width
+ * Buckets split the x range and not the point count, so gaps in time stay gaps
+ * @method downsampleIndexes
+ * @param {array} xValues
+ * @param {array} yValues
+ * @param {number} maxPoints - upper bound of kept points
+ * @returns {array} - kept indexes in original order
+ */
+pub fn downsampleIndexes(xValues: &[f64], yValues: &[f64], maxPoints: usize) -> Vec<usize> {
+    let length = xValues.len().min(yValues.len());
+
+    if length <= maxPoints {
+        return (0..length).collect();
+    }
+
+    let first = xValues[..length]
+        .iter()
+        .cloned()
+        .fold(f64::INFINITY, f64::min);
+    let last = xValues[..length]
+        .iter()
+        .cloned()
+        .fold(f64::NEG_INFINITY, f64::max);
+    let span = last - first;
+
+    // Two points per bucket
+    let bucketCount = (maxPoints / 2).max(1);
+
+    // Every bucket keeps its lowest and highest point, as [index, y]
+    let mut buckets: Vec<Option<([usize; 2], [f64; 2])>> = vec![None; bucketCount];
+
+    for i in 0..length {
+        let bucket = if span > 0.0 {
+            (((xValues[i] - first) / span * bucketCount as f64) as usize).min(bucketCount - 1)
+        } else {
+            0
+        };
+        let y = yValues[i];
+
+        buckets[bucket] = match buckets[bucket] {
+            None => Some(([i, i], [y, y])),
+            Some(([minIndex, maxIndex], [minY, maxY])) => Some((
+                [
+                    if y < minY { i } else { minIndex },
+                    if y > maxY { i } else { maxIndex },
+                ],
+                [minY.min(y), maxY.max(y)],
+            )),
+        };
+    }
+
+    let mut indexes: Vec<usize> = buckets
+        .into_iter()
+        .flatten()
+        .flat_map(|(pair, _)| pair)
+        .collect();
+
+    indexes.sort_unstable();
+    indexes.dedup();
+
+    indexes
+}
+
+/**
+ * Split preprocessed points per group and downsample every group for drawing
+ * @method groupsDisplayPoints
+ * @param {array} preprocessGroups - points of all groups in group order
+ * @param {array} groups - filtered groups the points come from
+ * @param {object} config
+ * @returns {array} - points to draw per group
+ */
+pub fn groupsDisplayPoints(
+    preprocessGroups: &[PreprocessItemStruct],
+    groups: &[GroupItemStruct],
+    config: &ConfigStruct,
+) -> Vec<Vec<PreprocessItemStruct>> {
+    let mut start = 0;
+
+    groups
+        .iter()
+        .map(|group| {
+            let length = group
+                .values
+                .as_ref()
+                .map(|values| values.len())
+                .unwrap_or(0);
+            let end = (start + length).min(preprocessGroups.len());
+            let points = &preprocessGroups[start.min(end)..end];
+            start = end;
+
+            match config.maxPoints {
+                Some(maxPoints) => {
+                    let xValues: Vec<f64> = points.iter().map(|point| point.x).collect();
+                    let yValues: Vec<f64> = points.iter().map(|point| point.y).collect();
+
+                    downsampleIndexes(&xValues, &yValues, maxPoints as usize)
+                        .into_iter()
+                        .map(|i| points[i].clone())
+                        .collect()
+                }
+                None => points.to_vec(),
+            }
+        })
+        .collect()
+}
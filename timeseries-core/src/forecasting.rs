@@ -0,0 +1,259 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{autocorrelation::*, consts::*, statistics::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Forecast by least squares linear trend on the value index
+ * @method linearTrendForecast
+ * @param {array} values - at least 3 values
+ * @param {number} horizon - number of future values
+ * @returns {array} - [means, standard errors, degrees of freedom]
+ */
+pub fn linearTrendForecast(values: &[f64], horizon: usize) -> (Vec<f64>, Vec<f64>, Option<f64>) {
+    let n = values.len() as f64;
+    let tMean = (n - 1.0) / 2.0;
+    let yMean = mean(values);
+
+    let sxx: f64 = (0..values.len()).map(|t| (t as f64 - tMean).powi(2)).sum();
+    let sxy: f64 = values
+        .iter()
+        .enumerate()
+        .map(|(t, y)| (t as f64 - tMean) * (y - yMean))
+        .sum();
+
+    let slope = sxy / sxx;
+    let intercept = yMean - slope * tMean;
+
+    let residuals: f64 = values
+        .iter()
+        .enumerate()
+        .map(|(t, y)| (y - intercept - slope * t as f64).powi(2))
+        .sum();
+    let sigma = (residuals / (n - 2.0)).sqrt();
+
+    let (means, standardErrors) = (1..=horizon)
+        .map(|h| {
+            let t = n - 1.0 + h as f64;
+
+            (
+                intercept + slope * t,
+                sigma * (1.0 + 1.0 / n + (t - tMean).powi(2) / sxx).sqrt(),
+            )
+        })
+        .unzip();
+
+    (means, standardErrors, Some(n - 2.0))
+}
+
+/**
+ * Forecast by Holt's double exponential smoothing
+ * @method holtForecast
+ * @param {array} values - at least 3 values
+ * @param {number} horizon - number of future values
+ * @param {number} alpha - level smoothing in (0, 1)
+ * @param {number} beta - trend smoothing in (0, 1)
+ * @returns {array} - [means, standard errors, degrees of freedom]
+ */
+pub fn holtForecast(
+    values: &[f64],
+    horizon: usize,
+    alpha: f64,
+    beta: f64,
+) -> (Vec<f64>, Vec<f64>, Option<f64>) {
+    let mut level = values[0];
+    let mut trend = values[1] - values[0];
+    let mut squaredErrors: Vec<f64> = Vec::new();
+
+    for value in values.iter().skip(1) {
+        let forecast = level + trend;
+        let previousLevel = level;
+
+        squaredErrors.push((value - forecast).powi(2));
+
+        level = alpha * value + (1.0 - alpha) * (level + trend);
+        trend = beta * (level - previousLevel) + (1.0 - beta) * trend;
+    }
+
+    let sigma = mean(&squaredErrors).sqrt();
+
+    // Variance of the equivalent additive-trend state space model
+    let (means, standardErrors) = (1..=horizon)
+        .map(|h| {
+            let spread: f64 = (1..h)
+                .map(|j| (alpha * (1.0 + beta * j as f64)).powi(2))
+                .sum();
+
+            (level + h as f64 * trend, sigma * (1.0 + spread).sqrt())
+        })
+        .unzip();
+
+    (means, standardErrors, None)
+}
+
+/**
+ * Forecast by autoregressive model fitted with Yule-Walker equations
+ * @method arForecast
+ * @param {array} values - more values than order
+ * @param {number} horizon - number of future values
+ * @param {number} order - number of lags p
+ * @returns {array} - [means, standard errors, degrees of freedom]
+ */
+pub fn arForecast(
+    values: &[f64],
+    horizon: usize,
+    order: usize,
+) -> (Vec<f64>, Vec<f64>, Option<f64>) {
+    let m = mean(values);
+    let n = values.len() as f64;
+    let acf = autocorrelation(values, order);
+    let (phi, _, innovation) = durbinLevinson(&acf, order);
+    let p = phi.len();
+
+    let seriesVariance = values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / n;
+    let sigma = (innovation * seriesVariance).max(0.0).sqrt();
+
+    // Recursive forecast of the demeaned series
+    let mut history: Vec<f64> = values.iter().map(|v| v - m).collect();
+    let mut means: Vec<f64> = Vec::new();
+
+    for _ in 0..horizon {
+        let next: f64 = (0..p)
+            .map(|i| phi[i] * history[history.len() - 1 - i])
+            .sum();
+
+        history.push(next);
+        means.push(next + m);
+    }
+
+    // Psi weights of the moving average representation
+    let mut psi: Vec<f64> = vec![1.0];
+
+    for j in 1..horizon {
+        psi.push((1..=j.min(p)).map(|i| phi[i - 1] * psi[j - i]).sum());
+    }
+
+    let mut cumulative = 0.0;
+    let standardErrors = psi
+        .iter()
+        .map(|weight| {
+            cumulative += weight * weight;
+
+            sigma * cumulative.sqrt()
+        })
+        .collect();
+
+    (means, standardErrors, None)
+}
+
+/**
+ * Get first forecast step beyond a limit
+ * @method limitCrossing
+ * @param {array} means - forecast means
+ * @param {number} start - index of the first forecast
+ * @param {function} beyond - true when a value is beyond the limit
+ * @returns {number} - index of the crossing, null when the forecast stays inside
+ */
+fn limitCrossing(means: &[f64], start: usize, beyond: impl Fn(f64) -> bool) -> Option<u32> {
+    means
+        .iter()
+        .position(|value| beyond(*value))
+        .map(|h| (start + h) as u32)
+}
+
+/**
+ * Forecast next values of group with prediction intervals
+ * @method forecastGroup
+ * @param {object} group - group returned by filterGroup
+ * @param {object} config
+ * @param {number} hiLimit
+ * @param {number} loLimit
+ * @returns {object} - null when the group has too few values for the model
+ */
+pub fn forecastGroup(
+    group: &GroupItemStruct,
+    config: &ConfigStruct,
+    hiLimit: Option<f64>,
+    loLimit: Option<f64>,
+) -> Option<ForecastStruct> {
+    let horizon = config.forecastHorizon? as usize;
+    let model = config
+        .forecastModel
+        .clone()
+        .unwrap_or(DEFAULT_FORECAST_MODEL.to_string());
+    let order = config.arOrder.unwrap_or(DEFAULT_AR_ORDER).max(1) as usize;
+
+    let values = group.values.clone().unwrap_or(vec![]);
+
+    if values.len() < 3 || (model == "ar" && values.len() <= order) {
+        return None;
+    }
+
+    let (means, standardErrors, df) = match model.as_str() {
+        "holt" => holtForecast(
+            &values,
+            horizon,
+            config.holtAlpha.unwrap_or(DEFAULT_HOLT_ALPHA),
+            config.holtBeta.unwrap_or(DEFAULT_HOLT_BETA),
+        ),
+        "ar" => arForecast(&values, horizon, order),
+        _ => linearTrendForecast(&values, horizon),
+    };
+
+    let quantile = |p: f64| match df {
+        Some(df) => studentTQuantile(p, df),
+        None => normalQuantile(p),
+    };
+    let z80 = quantile(0.9);
+    let z95 = quantile(0.975);
+
+    let interval = |z: f64, sign: f64| -> Vec<f64> {
+        means
+            .iter()
+            .zip(standardErrors.iter())
+            .map(|(m, se)| m + sign * z * se)
+            .collect()
+    };
+
+    Some(ForecastStruct {
+        model,
+        x: extrapolateXValues(&groupXValues(group), horizon),
+        lower80: interval(z80, -1.0),
+        upper80: interval(z80, 1.0),
+        lower95: interval(z95, -1.0),
+        upper95: interval(z95, 1.0),
+        hiLimitCrossing: hiLimit
+            .and_then(|hi| limitCrossing(&means, values.len(), |value| value > hi)),
+        loLimitCrossing: loLimit
+            .and_then(|lo| limitCrossing(&means, values.len(), |value| value < lo)),
+        mean: means,
+    })
+}
+
+/**
+ * Forecast every group
+ * @method groupsForecast
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @param {number} hiLimit
+ * @param {number} loLimit
+ * @returns {array} - empty when forecastHorizon is not set
+ */
+pub fn groupsForecast(
+    groups: Vec<GroupItemStruct>,
+    config: &ConfigStruct,
+    hiLimit: Option<f64>,
+    loLimit: Option<f64>,
+) -> Vec<Option<ForecastStruct>> {
+    if config.forecastHorizon.is_none() {
+        return vec![];
+    }
+
+    groups
+        .iter()
+        .map(|group| forecastGroup(group, config, hiLimit, loLimit))
+        .collect()
+}
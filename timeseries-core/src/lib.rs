@@ -0,0 +1,33 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+pub mod autocorrelation;
+pub mod box_plot;
+pub mod change_points;
+pub mod colors;
+pub mod consts;
+pub mod control_charts;
+pub mod csv_import;
+pub mod data_preprocess;
+pub mod decomposition;
+pub mod downsampling;
+pub mod forecasting;
+pub mod number_parsing;
+pub mod outliers;
+pub mod palettes;
+pub mod pipeline;
+pub mod raster;
+pub mod render;
+pub mod resampling;
+pub mod rolling_stats;
+pub mod site_comparison;
+pub mod smoothing;
+pub mod statistics;
+pub mod structs;
+pub mod summary;
+pub mod timestamps;
+pub mod tooltip;
+pub mod units;
+pub mod utils;
+pub mod validation;
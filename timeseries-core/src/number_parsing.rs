@@ -0,0 +1,317 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{structs::*, units::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get scale of SI prefix
+ * @method siPrefixScale
+ * @param {string} prefix - p, n, µ/u, m, k, M or G
+ * @returns {number} - null when prefix is unknown
+ */
+pub fn siPrefixScale(prefix: char) -> Option<f64> {
+    match prefix {
+        'p' => Some(1e-12),
+        'n' => Some(1e-9),
+        // Micro sign and Greek small letter mu look the same
+        'µ' | 'μ' | 'u' => Some(1e-6),
+        'm' => Some(1e-3),
+        'k' => Some(1e3),
+        'M' => Some(1e6),
+        'G' => Some(1e9),
+        _ => None,
+    }
+}
+
+/**
+ * Parse number in engineering notation like "−440.9mV", "834.7 µV" or "1.2e3"
+ * @method parseEngineering
+ * @param {string} text
+ * @returns {array} - [value in base unit, resolution of the last digit in base unit, unit],
+ *                    null when text doesn't start with a number
+ */
+pub fn parseEngineering(text: &str) -> Option<(f64, f64, String)> {
+    // Unicode minus is used by the formatted stats
+    let text = text.trim().replace('\u{2212}', "-");
+    let chars: Vec<char> = text.chars().collect();
+
+    let mut end = 0;
+
+    if end < chars.len() && (chars[end] == '-' || chars[end] == '+') {
+        end += 1;
+    }
+
+    let integerStart = end;
+
+    while end < chars.len() && chars[end].is_ascii_digit() {
+        end += 1;
+    }
+
+    let mut decimals = 0;
+
+    if end < chars.len() && chars[end] == '.' {
+        end += 1;
+
+        while end < chars.len() && chars[end].is_ascii_digit() {
+            end += 1;
+            decimals += 1;
+        }
+    }
+
+    if end == integerStart || (end == integerStart + 1 && chars[integerStart] == '.') {
+        return None;
+    }
+
+    let mantissaEnd = end;
+    let mut exponent = 0;
+
+    // Exponent only when "e" is followed by digits, "5e" keeps "e" as unit
+    if end < chars.len() && (chars[end] == 'e' || chars[end] == 'E') {
+        let mut exponentEnd = end + 1;
+
+        if exponentEnd < chars.len() && (chars[exponentEnd] == '-' || chars[exponentEnd] == '+') {
+            exponentEnd += 1;
+        }
+
+        let digitsStart = exponentEnd;
+
+        while exponentEnd < chars.len() && chars[exponentEnd].is_ascii_digit() {
+            exponentEnd += 1;
+        }
+
+        if exponentEnd > digitsStart {
+            exponent = chars[end + 1..exponentEnd]
+                .iter()
+                .collect::<String>()
+                .parse::<i32>()
+                .ok()?;
+            end = exponentEnd;
+        }
+    }
+
+    let mantissa: f64 = chars[..mantissaEnd]
+        .iter()
+        .collect::<String>()
+        .parse()
+        .ok()?;
+
+    let suffix: String = chars[end..].iter().collect::<String>().trim().to_string();
+    let mut suffixChars = suffix.chars();
+
+    // A leading prefix letter followed by a unit, or standing alone, scales the value
+    let (scale, unit) = match suffixChars.next().and_then(siPrefixScale) {
+        Some(scale) => (scale, suffixChars.collect::<String>()),
+        None => (1.0, suffix.clone()),
+    };
+
+    let magnitude = 10f64.powi(exponent) * scale;
+
+    Some((
+        mantissa * magnitude,
+        10f64.powi(-decimals) * magnitude,
+        unit,
+    ))
+}
+
+/**
+ * Get factor converting a value of parseEngineering into the units of data
+ * @method unitsFactor
+ * @param {string} text - formatted string
+ * @param {string} unit - unit parsed from text, its SI prefix already applied to the value
+ * @param {string} units - UNITS of data, null when not known
+ * @returns {number} - error when the unit of text differs from units
+ */
+pub fn unitsFactor(text: &str, unit: &str, units: Option<&str>) -> Result<f64, String> {
+    let units = match units.map(|units| units.trim()) {
+        Some(units) if !units.is_empty() => units,
+        _ => return Ok(1.0),
+    };
+
+    // Plain numbers are already in UNITS
+    if text.trim().replace('\u{2212}', "-").parse::<f64>().is_ok() {
+        return Ok(1.0);
+    }
+
+    let mismatch = || format!("unit of \"{}\" differs from UNITS {}", text, units);
+
+    match parseUnit(units) {
+        // A prefix without unit, like "12.5m", is a prefix of the unit of data
+        Ok((scale, base)) => {
+            if unit.is_empty() || parseUnit(unit) == Ok((1.0, base)) {
+                Ok(1.0 / scale)
+            } else {
+                Err(mismatch())
+            }
+        }
+        Err(_) if unit == units => Ok(1.0),
+        Err(_) => Err(mismatch()),
+    }
+}
+
+/**
+ * Fill missing numeric stats from their formatted strings and check both agree
+ * @method fillGroupStats
+ * @param {object} stats
+ * @param {number} group - index of the group in data, used by warnings
+ * @param {string} units - UNITS of data, strings of Max, Mean, Min and Std are rescaled into it
+ * @returns {array} - [stats, warnings]
+ */
+pub fn fillGroupStats(
+    stats: StatsGroupStruct,
+    group: Option<u32>,
+    units: Option<&str>,
+) -> (StatsGroupStruct, Vec<WarningStruct>) {
+    let mut warnings: Vec<WarningStruct> = Vec::new();
+
+    let mut fill =
+        |name: &str, text: &Option<String>, number: Option<f64>, hasUnit: bool| -> Option<f64> {
+            let parsed = match text.as_deref() {
+                Some(text) => match parseEngineering(text) {
+                    Some(parsed) => parsed,
+                    None => {
+                        warnings.push(WarningStruct {
+                            group,
+                            field: name.to_string(),
+                            message: format!("\"{}\" is not a number", text),
+                        });
+
+                        return number;
+                    }
+                },
+                None => return number,
+            };
+
+            // Cp and Cpk are ratios, their strings have no unit
+            let factor = if hasUnit {
+                match unitsFactor(text.as_deref().unwrap_or_default(), &parsed.2, units) {
+                    Ok(factor) => factor,
+                    Err(message) => {
+                        warnings.push(WarningStruct {
+                            group,
+                            field: name.to_string(),
+                            message,
+                        });
+
+                        return number;
+                    }
+                }
+            } else {
+                1.0
+            };
+
+            let (value, resolution) = (parsed.0 * factor, parsed.1 * factor);
+
+            match number {
+                None => Some(value),
+                Some(number) => {
+                    // The string is rounded to its last digit
+                    if (number - value).abs() > resolution + 1e-12 * number.abs().max(value.abs()) {
+                        warnings.push(WarningStruct {
+                            group,
+                            field: name.to_string(),
+                            message: format!(
+                                "\"{}\" disagrees with numeric value {}",
+                                text.clone().unwrap_or_default(),
+                                number
+                            ),
+                        });
+                    }
+
+                    Some(number)
+                }
+            }
+        };
+
+    let returnedStats = StatsGroupStruct {
+        cp: fill("Cp", &stats.Cp, stats.cp, false),
+        cpk: fill("Cpk", &stats.Cpk, stats.cpk, false),
+        max: fill("Max", &stats.Max, stats.max, true),
+        mean: fill("Mean", &stats.Mean, stats.mean, true),
+        min: fill("Min", &stats.Min, stats.min, true),
+        std: fill("Std", &stats.Std, stats.std, true),
+        ..stats
+    };
+
+    (returnedStats, warnings)
+}
+
+/**
+ * Fill missing numeric stats of every group
+ * @method groupsFillStats
+ * @param {array} groups - groups of data
+ * @param {string} units - UNITS of data
+ * @returns {array} - [groups, warnings]
+ */
+pub fn groupsFillStats(
+    groups: Vec<GroupItemStruct>,
+    units: Option<&str>,
+) -> (Vec<GroupItemStruct>, Vec<WarningStruct>) {
+    let mut warnings: Vec<WarningStruct> = Vec::new();
+
+    let returnedGroups = groups
+        .into_iter()
+        .enumerate()
+        .map(|(i, group)| match group.stats {
+            Some(stats) => {
+                let (stats, groupWarnings) = fillGroupStats(stats, Some(i as u32), units);
+
+                warnings.extend(groupWarnings);
+
+                GroupItemStruct {
+                    stats: Some(stats),
+                    ..group
+                }
+            }
+            None => group,
+        })
+        .collect();
+
+    (returnedGroups, warnings)
+}
+
+/**
+ * Format number in engineering notation like the stats strings, "−440.9mV" or "834.7µV"
+ * @method formatEngineering
+ * @param {number} value
+ * @param {string} unit - appended after the SI prefix
+ * @returns {string} - 4 significant digits with Unicode minus
+ */
+pub fn formatEngineering(value: f64, unit: &str) -> String {
+    if !value.is_finite() {
+        return format!("{}{}", value, unit);
+    }
+
+    let prefixes = ["p", "n", "µ", "m", "", "k", "M", "G"];
+    let mut exponent = if value == 0.0 {
+        0
+    } else {
+        ((value.abs().log10() / 3.0).floor() as i32).clamp(-4, 3)
+    };
+
+    let mut mantissa = value / 10f64.powi(3 * exponent);
+    let mut decimals = 4 - (mantissa.abs().log10().floor() as i32 + 1).clamp(1, 3);
+
+    // Rounding may carry the mantissa to the next prefix, 999.96 is 1.000k
+    if (mantissa.abs() * 10f64.powi(decimals)).round() >= 1e4 && exponent < 3 {
+        exponent += 1;
+        mantissa = value / 10f64.powi(3 * exponent);
+        decimals = 3;
+    }
+
+    let text = format!(
+        "{:.*}{}{}",
+        decimals as usize,
+        mantissa.abs(),
+        prefixes[(exponent + 4) as usize],
+        unit
+    );
+
+    if value < 0.0 {
+        format!("\u{2212}{}", text)
+    } else {
+        text
+    }
+}
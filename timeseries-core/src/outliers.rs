@@ -0,0 +1,305 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Flag values outside the IQR fences
+ * @method iqrOutliers
+ * @param {array} values
+ * @param {number} factor - fence distance in IQR
+ * @returns {array} - outlier flag per value
+ */
+pub fn iqrOutliers(values: &[f64], factor: f64) -> Vec<bool> {
+    let sorted = sortedValues(values);
+
+    if sorted.is_empty() {
+        return vec![false; values.len()];
+    }
+
+    let q1 = quantile(&sorted, 0.25);
+    let q3 = quantile(&sorted, 0.75);
+    let iqr = q3 - q1;
+
+    values
+        .iter()
+        .map(|v| *v < q1 - factor * iqr || *v > q3 + factor * iqr)
+        .collect()
+}
+
+/**
+ * Flag values whose robust z-score (median absolute deviation) exceeds threshold
+ * @method madOutliers
+ * @param {array} values
+ * @param {number} threshold - robust z-score limit
+ * @returns {array} - outlier flag per value
+ */
+pub fn madOutliers(values: &[f64], threshold: f64) -> Vec<bool> {
+    let sorted = sortedValues(values);
+
+    if sorted.is_empty() {
+        return vec![false; values.len()];
+    }
+
+    let center = median(&sorted);
+    let deviations: Vec<f64> = sorted.iter().map(|v| (v - center).abs()).collect();
+    let mad = median(&sortedValues(&deviations));
+
+    // All values identical to the median, nothing can be flagged
+    if mad == 0.0 {
+        return vec![false; values.len()];
+    }
+
+    values
+        .iter()
+        .map(|v| (MAD_Z_SCALE * (v - center) / mad).abs() > threshold)
+        .collect()
+}
+
+/**
+ * Get index of value with the largest absolute deviation from mean among kept values
+ * @method mostExtremeIndex
+ * @param {array} values
+ * @param {array} kept - flag per value, false when value already removed
+ * @returns {array} - [index, studentized deviation]
+ */
+fn mostExtremeIndex(values: &[f64], kept: &[bool]) -> Option<(usize, f64)> {
+    let remaining: Vec<f64> = values
+        .iter()
+        .zip(kept.iter())
+        .filter(|(_, k)| **k)
+        .map(|(v, _)| *v)
+        .collect();
+
+    let m = mean(&remaining);
+    let s = standardDeviation(&remaining);
+
+    if s.is_nan() || s == 0.0 {
+        return None;
+    }
+
+    values
+        .iter()
+        .enumerate()
+        .filter(|(i, _)| kept[*i])
+        .map(|(i, v)| (i, (v - m).abs() / s))
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap())
+}
+
+/**
+ * Get two-sided Grubbs critical value
+ * @method grubbsCritical
+ * @param {number} n - sample size
+ * @param {number} alpha - significance level
+ * @returns {number}
+ */
+pub fn grubbsCritical(n: usize, alpha: f64) -> f64 {
+    let n = n as f64;
+    let t = studentTQuantile(1.0 - alpha / (2.0 * n), n - 2.0);
+
+    (n - 1.0) / n.sqrt() * (t * t / (n - 2.0 + t * t)).sqrt()
+}
+
+/**
+ * Flag outliers by repeating two-sided Grubbs' test until no outlier is found
+ * @method grubbsOutliers
+ * @param {array} values
+ * @param {number} alpha - significance level
+ * @returns {array} - outlier flag per value
+ */
+pub fn grubbsOutliers(values: &[f64], alpha: f64) -> Vec<bool> {
+    let mut kept = vec![true; values.len()];
+
+    loop {
+        let n = kept.iter().filter(|k| **k).count();
+
+        if n < 3 {
+            break;
+        }
+
+        match mostExtremeIndex(values, &kept) {
+            Some((index, g)) if g > grubbsCritical(n, alpha) => kept[index] = false,
+            _ => break,
+        }
+    }
+
+    kept.iter().map(|k| !k).collect()
+}
+
+/**
+ * Flag outliers by Rosner's generalized extreme studentized deviate test
+ * @method esdOutliers
+ * @param {array} values
+ * @param {number} alpha - significance level
+ * @param {number} maxOutliers - upper bound of the number of outliers
+ * @returns {array} - outlier flag per value
+ */
+pub fn esdOutliers(values: &[f64], alpha: f64, maxOutliers: usize) -> Vec<bool> {
+    let n = values.len();
+    let mut kept = vec![true; n];
+    let mut removed: Vec<usize> = Vec::new();
+    let mut outlierCount = 0;
+
+    for i in 1..=maxOutliers.min(n.saturating_sub(2)) {
+        let (index, r) = match mostExtremeIndex(values, &kept) {
+            Some(extreme) => extreme,
+            None => break,
+        };
+
+        let remaining = (n - i + 1) as f64;
+        let p = 1.0 - alpha / (2.0 * remaining);
+        let t = studentTQuantile(p, remaining - 2.0);
+        let lambda = (remaining - 1.0) * t / ((remaining - 2.0 + t * t) * remaining).sqrt();
+
+        kept[index] = false;
+        removed.push(index);
+
+        // Number of outliers is the largest i with R_i > lambda_i
+        if r > lambda {
+            outlierCount = i;
+        }
+    }
+
+    let mut flags = vec![false; n];
+
+    for index in removed.into_iter().take(outlierCount) {
+        flags[index] = true;
+    }
+
+    flags
+}
+
+/**
+ * Flag outliers of values with the selected method
+ * @method detectOutliers
+ * @param {array} values
+ * @param {string} method - "iqr", "mad", "grubbs", "esd" or "none"
+ * @param {object} config
+ * @returns {array} - outlier flag per value
+ */
+pub fn detectOutliers(values: &[f64], method: &str, config: &ConfigStruct) -> Vec<bool> {
+    let alpha = config.outlierAlpha.unwrap_or(DEFAULT_OUTLIER_ALPHA);
+
+    match method {
+        "iqr" => iqrOutliers(values, config.outlierThreshold.unwrap_or(TUKEY_FACTOR)),
+        "mad" => madOutliers(
+            values,
+            config.outlierThreshold.unwrap_or(DEFAULT_MAD_THRESHOLD),
+        ),
+        "grubbs" => grubbsOutliers(values, alpha),
+        "esd" => {
+            let maxOutliers = config
+                .outlierMaxCount
+                .map(|count| count as usize)
+                .unwrap_or(values.len() / 10 + 1);
+
+            esdOutliers(values, alpha, maxOutliers)
+        }
+        _ => vec![false; values.len()],
+    }
+}
+
+/**
+ * Get outlier flags of every group
+ * @method groupsOutliers
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @returns {array} - outlier flags per group
+ */
+pub fn groupsOutliers(groups: Vec<GroupItemStruct>, config: &ConfigStruct) -> Vec<Vec<bool>> {
+    let method = config
+        .outlierMethod
+        .clone()
+        .unwrap_or(DEFAULT_OUTLIER_METHOD.to_string());
+
+    groups
+        .into_iter()
+        .map(|group| detectOutliers(&group.values.unwrap_or(vec![]), &method, config))
+        .collect()
+}
+
+/**
+ * Get outlier points of every group
+ * @method outlierPoints
+ * @param {array} groups - groups returned by filterGroup
+ * @param {array} outlierFlags - flags returned by groupsOutliers
+ * @returns {array}
+ */
+pub fn outlierPoints(
+    groups: Vec<GroupItemStruct>,
+    outlierFlags: Vec<Vec<bool>>,
+) -> Vec<Vec<OutlierPointStruct>> {
+    groups
+        .into_iter()
+        .zip(outlierFlags)
+        .map(|(group, flags)| {
+            let xValues = groupXValues(&group);
+
+            group
+                .values
+                .unwrap_or(vec![])
+                .into_iter()
+                .zip(flags)
+                .enumerate()
+                .filter(|(_, (_, flag))| *flag)
+                .map(|(i, (value, _))| OutlierPointStruct {
+                    x: xValues[i],
+                    value,
+                })
+                .collect()
+        })
+        .collect()
+}
+
+/**
+ * Set outlier flag of preprocessed items, items are in the same order as groups values
+ * @method flagOutliers
+ * @param {array} preprocessGroups - items returned by preprocess
+ * @param {array} outlierFlags - flags returned by groupsOutliers
+ * @returns {array}
+ */
+pub fn flagOutliers(
+    preprocessGroups: Vec<PreprocessItemStruct>,
+    outlierFlags: Vec<Vec<bool>>,
+) -> Vec<PreprocessItemStruct> {
+    preprocessGroups
+        .into_iter()
+        .zip(outlierFlags.into_iter().flatten())
+        .map(|(item, outlier)| PreprocessItemStruct { outlier, ..item })
+        .collect()
+}
+
+/**
+ * Replace outlier values by NaN so they are skipped by stats but keep their x
+ * @method maskOutliers
+ * @param {array} groups - groups returned by filterGroup
+ * @param {array} outlierFlags - flags returned by groupsOutliers
+ * @returns {array}
+ */
+pub fn maskOutliers(
+    groups: Vec<GroupItemStruct>,
+    outlierFlags: Vec<Vec<bool>>,
+) -> Vec<GroupItemStruct> {
+    groups
+        .into_iter()
+        .zip(outlierFlags)
+        .map(|(group, flags)| {
+            let values: Vec<f64> = group
+                .values
+                .clone()
+                .unwrap_or(vec![])
+                .into_iter()
+                .zip(flags)
+                .map(|(value, flag)| if flag { f64::NAN } else { value })
+                .collect();
+
+            GroupItemStruct {
+                values: Some(values),
+                ..group
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,72 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get colours of a built-in palette
+ * @method paletteColors
+ * @param {string} name - "tableau10", "category10", "okabeIto" or "tolBright"
+ * @returns {array} - null when palette is unknown
+ */
+pub fn paletteColors(name: &str) -> Option<&'static [&'static str]> {
+    match name {
+        "tableau10" => Some(&PALETTE_TABLEAU10),
+        "category10" => Some(&PALETTE_CATEGORY10),
+        "okabeIto" => Some(&PALETTE_OKABE_ITO),
+        "tolBright" => Some(&PALETTE_TOL_BRIGHT),
+        _ => None,
+    }
+}
+
+/**
+ * Get palette colour of a group, picked by site number so a site keeps its colour across charts
+ * @method groupColor
+ * @param {object} keyValues
+ * @param {number} index - index of the group, used when it has no site number
+ * @param {array} palette
+ * @returns {string}
+ */
+pub fn groupColor(keyValues: Option<KeyValuesStruct>, index: usize, palette: &[&str]) -> String {
+    let key = keyValues
+        .and_then(|keyValues| keyValues.SITE_NUM)
+        .map(|site| site as usize)
+        .unwrap_or(index);
+
+    palette[key % palette.len()].to_string()
+}
+
+/**
+ * Assign palette colours to groups without color
+ * @method assignColors
+ * @param {array} groups - groups of data
+ * @param {object} config
+ * @returns {array} - groups, unchanged when palette is "none" or unknown
+ */
+pub fn assignColors(groups: Vec<GroupItemStruct>, config: &ConfigStruct) -> Vec<GroupItemStruct> {
+    let name = config
+        .palette
+        .clone()
+        .unwrap_or(DEFAULT_PALETTE.to_string());
+
+    let palette = match paletteColors(&name) {
+        Some(palette) => palette,
+        None => return groups,
+    };
+
+    groups
+        .into_iter()
+        .enumerate()
+        .map(|(i, group)| GroupItemStruct {
+            color: Some(
+                group
+                    .color
+                    .unwrap_or_else(|| groupColor(group.keyValues, i, palette)),
+            ),
+            ..group
+        })
+        .collect()
+}
@@ -0,0 +1,186 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{
+    autocorrelation::*, box_plot::*, change_points::*, colors::*, consts::*, control_charts::*,
+    data_preprocess::*, decomposition::*, downsampling::*, forecasting::*, number_parsing::*,
+    outliers::*, palettes::*, rolling_stats::*, site_comparison::*, smoothing::*, structs::*,
+    timestamps::*, units::*, utils::*, validation::*,
+};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Fill stats, convert units, validate stats and assign colours of data
+ * @method prepareData
+ * @param {object} data
+ * @param {object} config
+ * @returns {array} - [data, warnings], error when the display unit is not compatible
+ */
+pub fn prepareData(
+    data: DataStruct,
+    config: &ConfigStruct,
+) -> Result<(DataStruct, Vec<WarningStruct>), String> {
+    // Exporters may only send the formatted stats strings, they are read in the units of data
+    let units = data.info.as_ref().and_then(|info| info.UNITS.clone());
+    let (groups, mut warnings) = groupsFillStats(data.groups.unwrap_or(vec![]), units.as_deref());
+
+    // Values, limits and stats follow the display unit
+    let data = convertData(
+        DataStruct {
+            groups: Some(groups),
+            ..data
+        },
+        config,
+    )?;
+
+    // Stats that don't match the values are reported, the render goes on
+    warnings.extend(validateData(&data, config));
+
+    // Groups without color get a palette colour of their site
+    let groups = assignColors(data.groups.unwrap_or(vec![]), config);
+
+    warnings.extend(colorWarnings(&groups));
+
+    Ok((
+        DataStruct {
+            groups: Some(groups),
+            ..data
+        },
+        warnings,
+    ))
+}
+
+/**
+ * Process data for the plot, same result on every platform
+ * @method process
+ * @param {object} data
+ * @param {object} config
+ * @returns {object} - error when data can't be prepared
+ */
+pub fn process(data: DataStruct, config: &ConfigStruct) -> Result<ReturnValuesStruct, String> {
+    let (data, mut warnings) = prepareData(data, config)?;
+
+    let groups = data.groups.unwrap_or(vec![]);
+
+    // let hiddenGroups = config.hiddenGroups;
+    let hiddenGroups = config.hiddenGroups.clone().unwrap_or(vec![]);
+
+    let xMode = config.xMode.clone().unwrap_or(DEFAULT_X_MODE.to_string());
+
+    // In index mode timestamps are dropped so every x is the value index
+    let groups = applyXMode(groups, &xMode);
+
+    warnings.extend(timestampWarnings(&groups));
+
+    let filterGroups: Vec<GroupItemStruct> = filterGroup(groups, hiddenGroups);
+
+    let outlierFlags = groupsOutliers(filterGroups.clone(), config);
+
+    let changePoints = groupsChangePoints(filterGroups.clone(), config);
+
+    let (ewma, cusum) = groupsControlCharts(filterGroups.clone(), config);
+
+    let smoothing = groupsSmoothing(filterGroups.clone(), config);
+
+    let rollingStats = groupsRollingStats(filterGroups.clone(), config);
+
+    let autocorrelation = groupsAutocorrelation(filterGroups.clone(), config);
+
+    let forecasts = groupsForecast(
+        filterGroups.clone(),
+        config,
+        data.info.as_ref().and_then(|info| info.HI_LIMIT),
+        data.info.as_ref().and_then(|info| info.LO_LIMIT),
+    );
+
+    let decomposition = groupsDecomposition(filterGroups.clone(), config);
+
+    let outliers = outlierPoints(filterGroups.clone(), outlierFlags.clone());
+
+    let preprocessGroups = flagOutliers(preprocess(filterGroups.clone()), outlierFlags.clone());
+
+    let groupsValues = groupsValuesList(filterGroups.clone());
+
+    let glColors = groupsGlColors(filterGroups.clone());
+
+    // Outliers are kept in the plot but can be left out of stats and domain
+    let excludeOutliers = config.excludeOutliers.unwrap_or(false);
+
+    let statsGroups = if excludeOutliers {
+        maskOutliers(filterGroups.clone(), outlierFlags)
+    } else {
+        filterGroups.clone()
+    };
+
+    let domainGroups: Vec<PreprocessItemStruct> = preprocessGroups
+        .iter()
+        .filter(|item| !(excludeOutliers && item.outlier))
+        .cloned()
+        .collect();
+
+    let hasTimestamps = filterGroups.iter().any(|group| group.timestamps.is_some());
+
+    let domain = if xMode == "time" && hasTimestamps {
+        getTimeDomain(domainGroups)
+    } else {
+        getDomain(domainGroups)
+    };
+
+    // Downsample after the domain, so the axes still cover every point
+    let displayGroups = groupsDisplayPoints(&preprocessGroups, &filterGroups, config);
+
+    let whiskerMode = config
+        .whiskerMode
+        .clone()
+        .unwrap_or(DEFAULT_WHISKER_MODE.to_string());
+    let whiskerPercentiles = config
+        .whiskerPercentiles
+        .unwrap_or(DEFAULT_WHISKER_PERCENTILES);
+
+    let boxPlots = boxPlots(statsGroups.clone(), whiskerMode, whiskerPercentiles);
+
+    let comparisonAlpha = config.comparisonAlpha.unwrap_or(DEFAULT_COMPARISON_ALPHA);
+    let comparisonCorrection = config
+        .comparisonCorrection
+        .clone()
+        .unwrap_or(DEFAULT_COMPARISON_CORRECTION.to_string());
+
+    let siteComparison = siteComparison(statsGroups.clone(), comparisonAlpha, comparisonCorrection);
+
+    let info = data.info.unwrap_or(InfoStruct {
+        HI_LIMIT: None,
+        LO_LIMIT: None,
+        UNITS: None,
+    });
+
+    let HI_LIMIT = info.HI_LIMIT.unwrap_or(0.0);
+    let LO_LIMIT = info.LO_LIMIT.unwrap_or(0.0);
+
+    let shouldShowLimit = shouldShowLimit(HI_LIMIT, LO_LIMIT);
+
+    Ok(ReturnValuesStruct {
+        filterGroups,
+        statsGroups,
+        preprocessGroups,
+        displayGroups,
+        groupsValues,
+        domain,
+        shouldShowLimit,
+        boxPlots,
+        outliers,
+        siteComparison,
+        changePoints,
+        ewma,
+        cusum,
+        smoothing,
+        rollingStats,
+        autocorrelation,
+        forecasts,
+        decomposition,
+        warnings,
+        glColors,
+        info,
+    })
+}
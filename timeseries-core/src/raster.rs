@@ -0,0 +1,370 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{colors::*, consts::*, pipeline::*, render::*, structs::*};
+use tiny_skia::{Color, FillRule, Mask, Paint, Path, PathBuilder, Pixmap, Rect, Stroke, Transform};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get paint of a CSS colour, black when the colour is not valid
+ * @method colorPaint
+ * @param {string} color
+ * @returns {object}
+ */
+fn colorPaint(color: &str) -> Paint<'static> {
+    let [r, g, b, a] = parseColor(color).unwrap_or([0.0, 0.0, 0.0, 1.0]);
+    let mut paint = Paint::default();
+
+    paint.set_color(
+        Color::from_rgba(r as f32, g as f32, b as f32, a as f32).unwrap_or(Color::BLACK),
+    );
+    paint.anti_alias = true;
+
+    paint
+}
+
+/**
+ * Get columns of a character in the bitmap font
+ * @method glyphColumns
+ * @param {string} character
+ * @returns {array} - "?" for characters outside the font
+ */
+fn glyphColumns(character: char) -> [u8; 5] {
+    match character {
+        ' '..='~' => FONT_5X8[character as usize - ' ' as usize],
+        // Unicode minus of formatEngineering
+        '\u{2212}' => FONT_5X8['-' as usize - ' ' as usize],
+        'µ' | 'μ' => FONT_5X8_MICRO,
+        'Ω' | '\u{2126}' => FONT_5X8_OMEGA,
+        _ => FONT_5X8['?' as usize - ' ' as usize],
+    }
+}
+
+/**
+ * Get path of text in the bitmap font, a cell is a tenth of the font size
+ * @method textPath
+ * @param {string} text
+ * @param {array} position - baseline point of the anchor
+ * @param {string} anchor - start, middle or end
+ * @param {number} size - font size in px
+ * @returns {object} - null when text has no visible pixel
+ */
+fn textPath(text: &str, position: (f64, f64), anchor: &str, size: f64) -> Option<Path> {
+    let cell = (size / 10.0) as f32;
+    let count = text.chars().count() as f32;
+    let width = (count * 6.0 - 1.0).max(0.0) * cell;
+
+    let left = match anchor {
+        "middle" => position.0 as f32 - width / 2.0,
+        "end" => position.0 as f32 - width,
+        _ => position.0 as f32,
+    };
+    // Rows 0 to 6 sit above the baseline
+    let top = position.1 as f32 - 7.0 * cell;
+
+    let mut builder = PathBuilder::new();
+
+    for (i, character) in text.chars().enumerate() {
+        for (column, bits) in glyphColumns(character).iter().enumerate() {
+            for row in 0..8 {
+                if bits >> row & 1 == 1 {
+                    if let Some(rect) = Rect::from_xywh(
+                        left + (i as f32 * 6.0 + column as f32) * cell,
+                        top + row as f32 * cell,
+                        cell,
+                        cell,
+                    ) {
+                        builder.push_rect(rect);
+                    }
+                }
+            }
+        }
+    }
+
+    builder.finish()
+}
+
+/**
+ * Get path of the hatch lines covering a rectangle, same lines as the SVG pattern tile
+ * @method hatchPath
+ * @param {array} origin
+ * @param {array} size
+ * @returns {object} - null when the rectangle is empty
+ */
+fn hatchPath(origin: (f64, f64), size: (f64, f64)) -> Option<Path> {
+    if size.0 <= 0.0 || size.1 <= 0.0 {
+        return None;
+    }
+
+    // Lines through (20 + 16k, -4) going 36.5 left every 30 down
+    let slope = -36.5 / 30.0;
+    let (top, bottom) = (origin.1, origin.1 + size.1);
+    let xAt = |x0: f64, y: f64| x0 + slope * (y + 4.0);
+
+    let first = ((origin.0 - xAt(20.0, top)) / 16.0).floor() as i64;
+    let last = ((origin.0 + size.0 - xAt(20.0, bottom)) / 16.0).ceil() as i64;
+
+    let mut builder = PathBuilder::new();
+
+    for k in first..=last {
+        let x0 = 20.0 + 16.0 * k as f64;
+
+        builder.move_to(xAt(x0, top) as f32, top as f32);
+        builder.line_to(xAt(x0, bottom) as f32, bottom as f32);
+    }
+
+    builder.finish()
+}
+
+/**
+ * Get mask of a rectangle
+ * @method rectMask
+ * @param {array} origin
+ * @param {array} size
+ * @param {object} pixmap - size of the mask
+ * @param {object} transform
+ * @returns {object} - null when the rectangle is empty
+ */
+fn rectMask(
+    origin: (f64, f64),
+    size: (f64, f64),
+    pixmap: &Pixmap,
+    transform: Transform,
+) -> Option<Mask> {
+    let rect = Rect::from_xywh(
+        origin.0 as f32,
+        origin.1 as f32,
+        size.0 as f32,
+        size.1 as f32,
+    )?;
+    let mut mask = Mask::new(pixmap.width(), pixmap.height())?;
+
+    mask.fill_path(
+        &PathBuilder::from_rect(rect),
+        FillRule::Winding,
+        true,
+        transform,
+    );
+
+    Some(mask)
+}
+
+/**
+ * Draw a shape of the scene
+ * @method drawShape
+ * @param {object} pixmap
+ * @param {object} shape
+ * @param {object} transform - scale from CSS px to pixels
+ * @param {object} mask - clip of the plot area, null for axes
+ */
+fn drawShape(pixmap: &mut Pixmap, shape: &ShapeEnum, transform: Transform, mask: Option<&Mask>) {
+    match shape {
+        ShapeEnum::Line {
+            from,
+            to,
+            color,
+            width,
+        } => {
+            let mut builder = PathBuilder::new();
+
+            builder.move_to(from.0 as f32, from.1 as f32);
+            builder.line_to(to.0 as f32, to.1 as f32);
+
+            if let Some(path) = builder.finish() {
+                let stroke = Stroke {
+                    width: *width as f32,
+                    ..Stroke::default()
+                };
+
+                pixmap.stroke_path(&path, &colorPaint(color), &stroke, transform, mask);
+            }
+        }
+        ShapeEnum::Polyline {
+            points,
+            color,
+            width,
+        } => {
+            let mut builder = PathBuilder::new();
+
+            for (i, (x, y)) in points.iter().enumerate() {
+                if i == 0 {
+                    builder.move_to(*x as f32, *y as f32);
+                } else {
+                    builder.line_to(*x as f32, *y as f32);
+                }
+            }
+
+            if let Some(path) = builder.finish() {
+                let stroke = Stroke {
+                    width: *width as f32,
+                    ..Stroke::default()
+                };
+
+                pixmap.stroke_path(&path, &colorPaint(color), &stroke, transform, mask);
+            }
+        }
+        ShapeEnum::Circle {
+            center,
+            radius,
+            color,
+            filled,
+        } => {
+            if let Some(path) =
+                PathBuilder::from_circle(center.0 as f32, center.1 as f32, *radius as f32)
+            {
+                let paint = colorPaint(color);
+
+                if *filled {
+                    pixmap.fill_path(&path, &paint, FillRule::Winding, transform, mask);
+                }
+
+                pixmap.stroke_path(&path, &paint, &Stroke::default(), transform, mask);
+            }
+        }
+        ShapeEnum::Hatch {
+            origin,
+            size,
+            color,
+        } => {
+            // Hatch rectangles lie inside the plot area, their own mask clips them
+            if let (Some(path), Some(hatchMask)) = (
+                hatchPath(*origin, *size),
+                rectMask(*origin, *size, pixmap, transform),
+            ) {
+                let stroke = Stroke {
+                    width: 0.5,
+                    ..Stroke::default()
+                };
+
+                pixmap.stroke_path(
+                    &path,
+                    &colorPaint(color),
+                    &stroke,
+                    transform,
+                    Some(&hatchMask),
+                );
+            }
+        }
+        ShapeEnum::Text {
+            position,
+            text,
+            anchor,
+            color,
+            size,
+        } => {
+            if let Some(path) = textPath(text, *position, anchor, *size) {
+                pixmap.fill_path(
+                    &path,
+                    &colorPaint(color),
+                    FillRule::Winding,
+                    transform,
+                    mask,
+                );
+            }
+        }
+    }
+}
+
+/**
+ * Rasterise a scene
+ * @method sceneToPixmap
+ * @param {object} scene
+ * @param {number} scale - pixels per CSS px
+ * @returns {object} - error when the size is empty or above MAX_PIXMAP_PIXELS
+ */
+pub fn sceneToPixmap(scene: &SceneStruct, scale: f64) -> Result<Pixmap, String> {
+    let pixels = (scene.width * scale).round() * (scene.height * scale).round();
+
+    // Checked before allocating, a large size or dpi must not exhaust memory
+    if pixels.is_nan() || pixels > MAX_PIXMAP_PIXELS {
+        return Err(format!(
+            "{}x{} image at scale {} is above {} pixels",
+            scene.width, scene.height, scale, MAX_PIXMAP_PIXELS
+        ));
+    }
+
+    let width = (scene.width * scale).round() as u32;
+    let height = (scene.height * scale).round() as u32;
+
+    let mut pixmap =
+        Pixmap::new(width, height).ok_or(format!("Can't create {}x{} image", width, height))?;
+    let transform = Transform::from_scale(scale as f32, scale as f32);
+
+    pixmap.fill(Color::WHITE);
+
+    let (origin, size) = scene.plotArea;
+    let plotMask = rectMask(origin, size, &pixmap, transform);
+
+    for shape in scene.plot.iter() {
+        drawShape(&mut pixmap, shape, transform, plotMask.as_ref());
+    }
+    for shape in scene.axes.iter() {
+        drawShape(&mut pixmap, shape, transform, None);
+    }
+
+    Ok(pixmap)
+}
+
+/**
+ * Encode pixmap to PNG with its resolution
+ * @method encodePng
+ * @param {object} pixmap
+ * @param {number} dpi - written to the pHYs chunk
+ * @returns {array} - PNG bytes
+ */
+pub fn encodePng(pixmap: &Pixmap, dpi: f64) -> Result<Vec<u8>, String> {
+    let mut bytes: Vec<u8> = Vec::new();
+    let mut encoder = png::Encoder::new(&mut bytes, pixmap.width(), pixmap.height());
+
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    // pHYs stores pixels per metre
+    let pixelsPerMetre = (dpi / 0.0254).round() as u32;
+
+    encoder.set_pixel_dims(Some(png::PixelDimensions {
+        xppu: pixelsPerMetre,
+        yppu: pixelsPerMetre,
+        unit: png::Unit::Meter,
+    }));
+
+    // Pixmap is premultiplied, PNG is not
+    let data: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect();
+
+    let mut writer = encoder.write_header().map_err(|e| e.to_string())?;
+
+    writer.write_image_data(&data).map_err(|e| e.to_string())?;
+    writer.finish().map_err(|e| e.to_string())?;
+
+    Ok(bytes)
+}
+
+/**
+ * Render the timeseries plot of data to PNG
+ * @method renderPng
+ * @param {object} data
+ * @param {object} config - width and height in CSS px, dpi scales them to pixels
+ * @returns {array} - PNG bytes, error when data can't be processed
+ */
+pub fn renderPng(data: DataStruct, config: &ConfigStruct) -> Result<Vec<u8>, String> {
+    let dpi = config.dpi.unwrap_or(DEFAULT_DPI);
+
+    if !(dpi.is_finite() && dpi > 0.0) {
+        return Err(format!("dpi {} must be positive", dpi));
+    }
+
+    let result = process(data, config)?;
+    let pixmap = sceneToPixmap(&plotScene(&result, config), dpi / DEFAULT_DPI)?;
+
+    encodePng(&pixmap, dpi)
+}
@@ -0,0 +1,505 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, number_parsing::*, pipeline::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get round tick values between min and max, steps are 1, 2 or 5 times a power of ten
+ * @method niceTicks
+ * @param {number} min
+ * @param {number} max
+ * @param {number} count - wanted number of ticks
+ * @returns {array}
+ */
+pub fn niceTicks(min: f64, max: f64, count: usize) -> Vec<f64> {
+    let span = max - min;
+
+    if !span.is_finite() || span <= 0.0 {
+        return vec![min];
+    }
+
+    let raw = span / count.max(1) as f64;
+    let magnitude = 10f64.powf(raw.log10().floor());
+    let step = [1.0, 2.0, 5.0, 10.0]
+        .iter()
+        .map(|factor| factor * magnitude)
+        .find(|step| *step >= raw)
+        .unwrap_or(10.0 * magnitude);
+
+    tickRange(min, max, step)
+}
+
+/**
+ * Get multiples of step between min and max
+ * @method tickRange
+ * @param {number} min
+ * @param {number} max
+ * @param {number} step
+ * @returns {array}
+ */
+fn tickRange(min: f64, max: f64, step: f64) -> Vec<f64> {
+    let first = (min / step).ceil() as i64;
+    let last = (max / step + 1e-9).floor() as i64;
+
+    // Dividing by the inverse of a decimal step avoids -0.30000000000000004
+    if step < 1.0 {
+        let inverse = (1.0 / step).round();
+
+        return (first..=last).map(|i| i as f64 / inverse).collect();
+    }
+
+    (first..=last).map(|i| i as f64 * step).collect()
+}
+
+/**
+ * Get tick values of a time axis on round local times
+ * @method timeTicks
+ * @param {number} min - epoch milliseconds
+ * @param {number} max - epoch milliseconds
+ * @param {number} count - wanted number of ticks
+ * @param {number} offset - local time zone offset from UTC in milliseconds
+ * @returns {array} - [ticks in epoch milliseconds, step in milliseconds]
+ */
+pub fn timeTicks(min: f64, max: f64, count: usize, offset: f64) -> (Vec<f64>, f64) {
+    let raw = (max - min) / count.max(1) as f64;
+    let steps = [
+        1000.0,
+        5000.0,
+        15000.0,
+        30000.0,
+        MINUTE_MS,
+        5.0 * MINUTE_MS,
+        15.0 * MINUTE_MS,
+        30.0 * MINUTE_MS,
+        HOUR_MS,
+        3.0 * HOUR_MS,
+        6.0 * HOUR_MS,
+        12.0 * HOUR_MS,
+        DAY_MS,
+        2.0 * DAY_MS,
+        7.0 * DAY_MS,
+        30.0 * DAY_MS,
+    ];
+    let step = steps
+        .iter()
+        .copied()
+        .find(|step| *step >= raw)
+        .unwrap_or(30.0 * DAY_MS);
+
+    let ticks = tickRange(min + offset, max + offset, step)
+        .into_iter()
+        .map(|tick| tick - offset)
+        .collect();
+
+    (ticks, step)
+}
+
+/**
+ * Format tick of a time axis in local time
+ * @method formatTimeTick
+ * @param {number} time - epoch milliseconds
+ * @param {number} step - tick step in milliseconds
+ * @param {number} offset - local time zone offset from UTC in milliseconds
+ * @returns {string} - "MM-DD" for day steps, "MM-DD HH:MM" or "HH:MM:SS" otherwise
+ */
+pub fn formatTimeTick(time: f64, step: f64, offset: f64) -> String {
+    let local = time + offset;
+    let days = (local / DAY_MS).floor();
+    let (_, month, day) = civilFromDays(days as i64);
+    let seconds = ((local - days * DAY_MS) / 1000.0).round() as i64;
+
+    if step >= DAY_MS {
+        format!("{:02}-{:02}", month, day)
+    } else if step >= MINUTE_MS {
+        format!(
+            "{:02}-{:02} {:02}:{:02}",
+            month,
+            day,
+            seconds / 3600,
+            seconds / 60 % 60
+        )
+    } else {
+        format!(
+            "{:02}:{:02}:{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60
+        )
+    }
+}
+
+/**
+ * Format tick of a numeric axis with the decimals of its step
+ * @method formatNumberTick
+ * @param {number} value
+ * @param {number} step
+ * @returns {string}
+ */
+fn formatNumberTick(value: f64, step: f64) -> String {
+    let decimals = (-step.log10().floor()).max(0.0) as usize;
+
+    // Avoid "-0"
+    format!("{:.*}", decimals, value + 0.0)
+}
+
+/**
+ * Build the shapes of the timeseries plot, same layers as the front-end:
+ * gridlines, lines, limit band and markers clipped to the plot area, then axes
+ * @method plotScene
+ * @param {object} result - returned by process
+ * @param {object} config
+ * @returns {object}
+ */
+pub fn plotScene(result: &ReturnValuesStruct, config: &ConfigStruct) -> SceneStruct {
+    let width = config.width.unwrap_or(DEFAULT_PLOT_WIDTH) as f64;
+    let height = config.height.unwrap_or(DEFAULT_PLOT_HEIGHT) as f64;
+
+    let left = PLOT_MARGINS[0];
+    let top = PLOT_MARGINS[1];
+    let right = (width - PLOT_MARGINS[2]).max(left + 1.0);
+    let bottom = (height - PLOT_MARGINS[3]).max(top + 1.0);
+
+    let loLimit = result.info.LO_LIMIT;
+    let hiLimit = result.info.HI_LIMIT;
+    let units = result.info.UNITS.clone().unwrap_or_default();
+
+    let [x0, x1] = result.domain.0;
+
+    // "limit" range mode shows the limits, like the front-end
+    let [y0, y1] = match (config.valueRangeMode.as_deref(), loLimit, hiLimit) {
+        (Some("limit"), Some(lo), Some(hi)) if lo < hi => [lo, hi],
+        _ => result.domain.1,
+    };
+    let (x0, x1) = if x1 > x0 {
+        (x0, x1)
+    } else {
+        (x0 - 1.0, x0 + 1.0)
+    };
+    let (y0, y1) = if y1 > y0 {
+        (y0, y1)
+    } else {
+        (y0 - 1.0, y0 + 1.0)
+    };
+
+    let scaleX = |x: f64| left + (x - x0) / (x1 - x0) * (right - left);
+    let scaleY = |y: f64| bottom - (y - y0) / (y1 - y0) * (bottom - top);
+
+    let isTime = config.xMode.as_deref() == Some("time")
+        && result
+            .filterGroups
+            .iter()
+            .any(|group| group.timestamps.is_some());
+    let offset = config.timezoneOffset.unwrap_or(0.0) * MINUTE_MS;
+
+    let xTickCount = ((right - left) / 100.0).round().max(2.0) as usize;
+    let yTickCount = ((bottom - top) / 50.0).round().max(2.0) as usize;
+
+    let (xTicks, xLabels): (Vec<f64>, Vec<String>) = if isTime {
+        let (ticks, step) = timeTicks(x0, x1, xTickCount, offset);
+
+        ticks
+            .into_iter()
+            .map(|tick| (tick, formatTimeTick(tick, step, offset)))
+            .unzip()
+    } else {
+        let ticks = niceTicks(x0, x1, xTickCount);
+        let step = if ticks.len() > 1 {
+            ticks[1] - ticks[0]
+        } else {
+            1.0
+        };
+
+        ticks
+            .into_iter()
+            .map(|tick| (tick, formatNumberTick(tick, step)))
+            .unzip()
+    };
+    let yTicks = niceTicks(y0, y1, yTickCount);
+
+    let mut plot: Vec<ShapeEnum> = Vec::new();
+
+    // Gridlines
+    for tick in xTicks.iter() {
+        plot.push(ShapeEnum::Line {
+            from: (scaleX(*tick), top),
+            to: (scaleX(*tick), bottom),
+            color: GRID_COLOR.to_string(),
+            width: 1.0,
+        });
+    }
+    for tick in yTicks.iter() {
+        plot.push(ShapeEnum::Line {
+            from: (left, scaleY(*tick)),
+            to: (right, scaleY(*tick)),
+            color: GRID_COLOR.to_string(),
+            width: 1.0,
+        });
+    }
+
+    // Lines between points of every group
+    if config.drawLines.unwrap_or(false) {
+        for group in result.filterGroups.iter() {
+            let points: Vec<(f64, f64)> = groupXValues(group)
+                .into_iter()
+                .zip(group.values.clone().unwrap_or(vec![]))
+                .map(|(x, y)| (scaleX(x), scaleY(y)))
+                .collect();
+
+            plot.push(ShapeEnum::Polyline {
+                points,
+                color: group.color.clone().unwrap_or(DEFAULT_COLOR.to_string()),
+                width: 1.0,
+            });
+        }
+    }
+
+    // Hatched band beyond the limits
+    if config.limitHighlight {
+        let (lo, hi) = match (loLimit, hiLimit) {
+            (Some(lo), Some(hi)) if lo >= hi => (None, None),
+            limits => limits,
+        };
+
+        if let Some(hi) = hi {
+            let edge = scaleY(hi).clamp(top, bottom);
+
+            plot.push(ShapeEnum::Hatch {
+                origin: (left, top),
+                size: (right - left, edge - top),
+                color: LIMIT_HATCH_COLOR.to_string(),
+            });
+        }
+        if let Some(lo) = lo {
+            let edge = scaleY(lo).clamp(top, bottom);
+
+            plot.push(ShapeEnum::Hatch {
+                origin: (left, edge),
+                size: (right - left, bottom - edge),
+                color: LIMIT_HATCH_COLOR.to_string(),
+            });
+        }
+    }
+
+    // Markers, markerSize is an area like d3fc point series
+    let radius = (config.markerSize.unwrap_or(DEFAULT_MARKER_SIZE) as f64 / std::f64::consts::PI)
+        .sqrt()
+        .max(0.5);
+    let filled = config.marker.unwrap_or(false);
+
+    for item in result.preprocessGroups.iter() {
+        plot.push(ShapeEnum::Circle {
+            center: (scaleX(item.x), scaleY(item.y)),
+            radius,
+            color: item.color.clone(),
+            filled,
+        });
+    }
+
+    let mut axes: Vec<ShapeEnum> = vec![
+        ShapeEnum::Line {
+            from: (left, top),
+            to: (left, bottom),
+            color: AXIS_COLOR.to_string(),
+            width: 1.0,
+        },
+        ShapeEnum::Line {
+            from: (left, bottom),
+            to: (right, bottom),
+            color: AXIS_COLOR.to_string(),
+            width: 1.0,
+        },
+    ];
+
+    for (tick, label) in xTicks.iter().zip(xLabels) {
+        axes.push(ShapeEnum::Line {
+            from: (scaleX(*tick), bottom),
+            to: (scaleX(*tick), bottom + 4.0),
+            color: AXIS_COLOR.to_string(),
+            width: 1.0,
+        });
+        axes.push(ShapeEnum::Text {
+            position: (scaleX(*tick), bottom + 6.0 + TICK_FONT_SIZE),
+            text: label,
+            anchor: "middle".to_string(),
+            color: AXIS_COLOR.to_string(),
+            size: TICK_FONT_SIZE,
+        });
+    }
+    for tick in yTicks.iter() {
+        axes.push(ShapeEnum::Line {
+            from: (left - 4.0, scaleY(*tick)),
+            to: (left, scaleY(*tick)),
+            color: AXIS_COLOR.to_string(),
+            width: 1.0,
+        });
+        axes.push(ShapeEnum::Text {
+            position: (left - 6.0, scaleY(*tick) + TICK_FONT_SIZE / 3.0),
+            text: formatEngineering(*tick, &units),
+            anchor: "end".to_string(),
+            color: AXIS_COLOR.to_string(),
+            size: TICK_FONT_SIZE,
+        });
+    }
+
+    SceneStruct {
+        width,
+        height,
+        plotArea: ((left, top), (right - left, bottom - top)),
+        plot,
+        axes,
+    }
+}
+
+/**
+ * Escape text for XML
+ * @method escapeXml
+ * @param {string} text
+ * @returns {string}
+ */
+fn escapeXml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/**
+ * Get SVG element of a shape
+ * @method shapeToSvg
+ * @param {object} shape
+ * @param {number} index - makes the hatch pattern id unique
+ * @returns {string}
+ */
+fn shapeToSvg(shape: &ShapeEnum, index: usize) -> String {
+    match shape {
+        ShapeEnum::Line {
+            from,
+            to,
+            color,
+            width,
+        } => format!(
+            r#"<line x1="{:.2}" y1="{:.2}" x2="{:.2}" y2="{:.2}" stroke="{}" stroke-width="{}"/>"#,
+            from.0,
+            from.1,
+            to.0,
+            to.1,
+            escapeXml(color),
+            width
+        ),
+        ShapeEnum::Polyline {
+            points,
+            color,
+            width,
+        } => format!(
+            r#"<polyline points="{}" fill="none" stroke="{}" stroke-width="{}"/>"#,
+            points
+                .iter()
+                .map(|(x, y)| format!("{:.2},{:.2}", x, y))
+                .collect::<Vec<String>>()
+                .join(" "),
+            escapeXml(color),
+            width
+        ),
+        ShapeEnum::Circle {
+            center,
+            radius,
+            color,
+            filled,
+        } => {
+            let color = escapeXml(color);
+            let fill = if *filled { color.as_str() } else { "none" };
+
+            format!(
+                r#"<circle cx="{:.2}" cy="{:.2}" r="{:.2}" fill="{}" stroke="{}"/>"#,
+                center.0, center.1, radius, fill, color
+            )
+        }
+        // Same tile as renderLimitArea of the front-end
+        ShapeEnum::Hatch {
+            origin,
+            size,
+            color,
+        } => format!(
+            concat!(
+                r#"<pattern id="hatch-{}" width="16" height="26" patternUnits="userSpaceOnUse">"#,
+                r#"<path d="M36,-4L-0.5,26M20,-4L-16.5,26M52,-4L15.5,26" stroke="{}" stroke-width="0.5"/>"#,
+                r#"</pattern><rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}" fill="url(#hatch-{})"/>"#
+            ),
+            index,
+            escapeXml(color),
+            origin.0,
+            origin.1,
+            size.0,
+            size.1,
+            index
+        ),
+        ShapeEnum::Text {
+            position,
+            text,
+            anchor,
+            color,
+            size,
+        } => format!(
+            r#"<text x="{:.2}" y="{:.2}" text-anchor="{}" fill="{}" font-family="sans-serif" font-size="{}">{}</text>"#,
+            position.0,
+            position.1,
+            escapeXml(anchor),
+            escapeXml(color),
+            size,
+            escapeXml(text)
+        ),
+    }
+}
+
+/**
+ * Get standalone SVG document of a scene
+ * @method sceneToSvg
+ * @param {object} scene
+ * @returns {string}
+ */
+pub fn sceneToSvg(scene: &SceneStruct) -> String {
+    let ((left, top), (plotWidth, plotHeight)) = scene.plotArea;
+
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{w}" height="{h}" viewBox="0 0 {w} {h}">"#,
+        w = scene.width,
+        h = scene.height
+    );
+
+    svg.push_str(&format!(
+        r#"<defs><clipPath id="plot-area"><rect x="{:.2}" y="{:.2}" width="{:.2}" height="{:.2}"/></clipPath></defs>"#,
+        left, top, plotWidth, plotHeight
+    ));
+    svg.push_str(r##"<rect width="100%" height="100%" fill="#ffffff"/>"##);
+    svg.push_str(r#"<g clip-path="url(#plot-area)">"#);
+
+    for (i, shape) in scene.plot.iter().enumerate() {
+        svg.push_str(&shapeToSvg(shape, i));
+    }
+
+    svg.push_str("</g>");
+
+    for (i, shape) in scene.axes.iter().enumerate() {
+        svg.push_str(&shapeToSvg(shape, scene.plot.len() + i));
+    }
+
+    svg.push_str("</svg>");
+
+    svg
+}
+
+/**
+ * Render the timeseries plot of data to a standalone SVG
+ * @method renderSvg
+ * @param {object} data
+ * @param {object} config - width and height in px
+ * @returns {string} - error when data can't be processed
+ */
+pub fn renderSvg(data: DataStruct, config: &ConfigStruct) -> Result<String, String> {
+    let result = process(data, config)?;
+
+    Ok(sceneToSvg(&plotScene(&result, config)))
+}
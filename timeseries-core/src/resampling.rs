@@ -0,0 +1,248 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get start of the bucket containing time, buckets follow the local calendar
+ * @method bucketStart
+ * @param {number} time - epoch milliseconds
+ * @param {string} bucket - "minute", "hour", "shift", "day", "week" or "month"
+ * @param {number} offset - local time zone offset from UTC in milliseconds
+ * @param {number} shiftLength - shift length in milliseconds
+ * @param {number} shiftStart - local start of the first shift of the day in milliseconds
+ * @returns {number} - epoch milliseconds, null when bucket is unknown
+ */
+pub fn bucketStart(
+    time: f64,
+    bucket: &str,
+    offset: f64,
+    shiftLength: f64,
+    shiftStart: f64,
+) -> Option<f64> {
+    let local = time + offset;
+
+    let localStart = match bucket {
+        "minute" => (local / MINUTE_MS).floor() * MINUTE_MS,
+        "hour" => (local / HOUR_MS).floor() * HOUR_MS,
+        "shift" => ((local - shiftStart) / shiftLength).floor() * shiftLength + shiftStart,
+        "day" => (local / DAY_MS).floor() * DAY_MS,
+        // 1970-01-01 is a Thursday, weeks start on Monday
+        "week" => ((local + 3.0 * DAY_MS) / (7.0 * DAY_MS)).floor() * 7.0 * DAY_MS - 3.0 * DAY_MS,
+        "month" => {
+            let (year, month, _) = civilFromDays((local / DAY_MS).floor() as i64);
+
+            daysFromCivil(year, month, 1) as f64 * DAY_MS
+        }
+        _ => return None,
+    };
+
+    Some(localStart - offset)
+}
+
+/**
+ * Aggregate values of one bucket
+ * @method aggregateValues
+ * @param {array} values
+ * @param {string} aggregation - "mean", "median", "min", "max", "count", "std" or "yield"
+ * @param {number} loLimit - only for "yield"
+ * @param {number} hiLimit - only for "yield"
+ * @returns {number} - NaN when aggregation is unknown
+ */
+pub fn aggregateValues(
+    values: &[f64],
+    aggregation: &str,
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+) -> f64 {
+    match aggregation {
+        "mean" => mean(values),
+        "median" => median(&sortedValues(values)),
+        "min" => values.iter().copied().fold(f64::INFINITY, f64::min),
+        "max" => values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        "count" => values.len() as f64,
+        "std" => {
+            if values.len() < 2 {
+                0.0
+            } else {
+                standardDeviation(values)
+            }
+        }
+        "yield" => {
+            let passed = values
+                .iter()
+                .filter(|v| {
+                    loLimit.is_none_or(|lo| **v >= lo) && hiLimit.is_none_or(|hi| **v <= hi)
+                })
+                .count();
+
+            100.0 * passed as f64 / values.len() as f64
+        }
+        _ => f64::NAN,
+    }
+}
+
+/**
+ * Get bucket and aggregation of config, error when one of them is unknown
+ * @method resampleNames
+ * @param {object} config
+ * @returns {array} - [bucket, aggregation]
+ */
+pub fn resampleNames(config: &ConfigStruct) -> Result<(String, String), String> {
+    let bucket = config
+        .resampleBucket
+        .clone()
+        .unwrap_or(DEFAULT_RESAMPLE_BUCKET.to_string());
+    let aggregation = config
+        .resampleAggregation
+        .clone()
+        .unwrap_or(DEFAULT_RESAMPLE_AGGREGATION.to_string());
+
+    if !RESAMPLE_BUCKETS.contains(&bucket.as_str()) {
+        return Err(format!("Unknown resampleBucket: {}", bucket));
+    }
+    if !RESAMPLE_AGGREGATIONS.contains(&aggregation.as_str()) {
+        return Err(format!("Unknown resampleAggregation: {}", aggregation));
+    }
+
+    Ok((bucket, aggregation))
+}
+
+/**
+ * Aggregate values of group into time buckets
+ * @method resampleGroup
+ * @param {object} group
+ * @param {object} config
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {object} - group with one value per non-empty bucket, timestamped at bucket start,
+ *                     error when bucket or aggregation is unknown or shift buckets have no positive length
+ */
+pub fn resampleGroup(
+    group: GroupItemStruct,
+    config: &ConfigStruct,
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+) -> Result<GroupItemStruct, String> {
+    let (bucket, aggregation) = resampleNames(config)?;
+    let offset = config.timezoneOffset.unwrap_or(0.0) * MINUTE_MS;
+    let shiftLength = config.shiftHours.unwrap_or(DEFAULT_SHIFT_HOURS) * HOUR_MS;
+    let shiftStart = config.shiftStartHour.unwrap_or(DEFAULT_SHIFT_START_HOUR) * HOUR_MS;
+
+    if bucket == "shift" && !(shiftLength.is_finite() && shiftLength > 0.0) {
+        return Err(format!(
+            "shiftHours must be a positive number, got {}",
+            shiftLength / HOUR_MS
+        ));
+    }
+
+    let values = group.values.clone().unwrap_or(vec![]);
+
+    // Groups without a timestamp per value can't be placed in buckets
+    let timestamps = match &group.timestamps {
+        Some(timestamps) if timestamps.len() == values.len() => timestamps.clone(),
+        _ => return Ok(group),
+    };
+
+    // Collect (bucket start, value), skipping NaN values and invalid timestamps
+    let mut points: Vec<(f64, f64)> = values
+        .iter()
+        .zip(timestamps.iter())
+        .filter(|(value, _)| !value.is_nan())
+        .filter_map(|(value, timestamp)| {
+            let time = timestampToEpoch(timestamp)?;
+            let start = bucketStart(time, &bucket, offset, shiftLength, shiftStart)?;
+
+            // A NaN offset or shift start can't place the value
+            Some((start, *value)).filter(|_| start.is_finite())
+        })
+        .collect();
+
+    points.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let mut bucketValues: Vec<f64> = Vec::new();
+    let mut bucketTimestamps: Vec<TimestampEnum> = Vec::new();
+    let mut start = 0;
+
+    while start < points.len() {
+        let mut end = start;
+
+        while end + 1 < points.len() && points[end + 1].0 == points[start].0 {
+            end += 1;
+        }
+
+        let bucketPoints: Vec<f64> = points[start..=end].iter().map(|p| p.1).collect();
+
+        bucketValues.push(aggregateValues(
+            &bucketPoints,
+            &aggregation,
+            loLimit,
+            hiLimit,
+        ));
+        bucketTimestamps.push(TimestampEnum::Epoch(points[start].0));
+
+        start = end + 1;
+    }
+
+    // Stats of the raw values no longer describe the aggregated values
+    Ok(GroupItemStruct {
+        keyValues: group.keyValues,
+        color: group.color,
+        stats: None,
+        values: Some(bucketValues),
+        timestamps: Some(bucketTimestamps),
+    })
+}
+
+/**
+ * Aggregate every group of data into time buckets
+ * @method resampleData
+ * @param {object} data
+ * @param {object} config
+ * @returns {object} - new data that can be passed to main, error when a group can't be resampled
+ */
+pub fn resampleData(data: DataStruct, config: &ConfigStruct) -> Result<DataStruct, String> {
+    let (_, aggregation) = resampleNames(config)?;
+    let loLimit = data.info.as_ref().and_then(|info| info.LO_LIMIT);
+    let hiLimit = data.info.as_ref().and_then(|info| info.HI_LIMIT);
+
+    let groups = data
+        .groups
+        .map(|groups| {
+            groups
+                .into_iter()
+                .map(|group| resampleGroup(group, config, loLimit, hiLimit))
+                .collect::<Result<Vec<GroupItemStruct>, String>>()
+        })
+        .transpose()?;
+
+    // Limits only apply when aggregated values keep the measurement scale
+    let info = data.info.map(|info| match aggregation.as_str() {
+        "count" => InfoStruct {
+            HI_LIMIT: None,
+            LO_LIMIT: None,
+            UNITS: None,
+        },
+        "yield" => InfoStruct {
+            HI_LIMIT: None,
+            LO_LIMIT: None,
+            UNITS: Some("%".to_string()),
+        },
+        "std" => InfoStruct {
+            HI_LIMIT: None,
+            LO_LIMIT: None,
+            ..info
+        },
+        _ => info,
+    });
+
+    // Dataset stats describe raw values, they are dropped as well
+    Ok(DataStruct {
+        groups,
+        stats: None,
+        info,
+    })
+}
@@ -0,0 +1,140 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use std::collections::VecDeque;
+
+use crate::{consts::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get trailing rolling mean, std, min and max of values in a single pass, NaN values are skipped
+ * @method rollingStatistics
+ * @param {array} values
+ * @param {number} window - number of values in the window, NaN included
+ * @param {number} k - band half width in std
+ * @returns {object} - one point per value, NaN when the window holds no number
+ */
+pub fn rollingStatistics(values: &[f64], window: usize, k: f64) -> RollingStatsStruct {
+    let window = window.max(1);
+
+    let mut means: Vec<f64> = Vec::new();
+    let mut stds: Vec<f64> = Vec::new();
+    let mut mins: Vec<f64> = Vec::new();
+    let mut maxs: Vec<f64> = Vec::new();
+
+    // Welford accumulators of the current window
+    let mut count = 0.0;
+    let mut mean = 0.0;
+    let mut m2: f64 = 0.0;
+
+    // Monotonic queues of indexes, front is the window min or max
+    let mut minQueue: VecDeque<usize> = VecDeque::new();
+    let mut maxQueue: VecDeque<usize> = VecDeque::new();
+
+    for (i, value) in values.iter().enumerate() {
+        // A NaN would stay in the accumulators for the rest of the values
+        if !value.is_nan() {
+            count += 1.0;
+            let delta = value - mean;
+            mean += delta / count;
+            m2 += delta * (value - mean);
+        }
+
+        if i >= window && !values[i - window].is_nan() {
+            let removed = values[i - window];
+
+            count -= 1.0;
+
+            if count == 0.0 {
+                mean = 0.0;
+                m2 = 0.0;
+            } else {
+                let delta = removed - mean;
+                mean -= delta / count;
+                m2 -= delta * (removed - mean);
+            }
+        }
+
+        if !value.is_nan() {
+            while minQueue.back().is_some_and(|j| values[*j] >= *value) {
+                minQueue.pop_back();
+            }
+            while maxQueue.back().is_some_and(|j| values[*j] <= *value) {
+                maxQueue.pop_back();
+            }
+
+            minQueue.push_back(i);
+            maxQueue.push_back(i);
+        }
+
+        while minQueue.front().is_some_and(|j| j + window <= i) {
+            minQueue.pop_front();
+        }
+        while maxQueue.front().is_some_and(|j| j + window <= i) {
+            maxQueue.pop_front();
+        }
+
+        // Rounding can leave a tiny negative sum of squares
+        let std = if count > 1.0 {
+            (m2.max(0.0) / (count - 1.0)).sqrt()
+        } else {
+            0.0
+        };
+
+        if count > 0.0 {
+            means.push(mean);
+            stds.push(std);
+        } else {
+            means.push(f64::NAN);
+            stds.push(f64::NAN);
+        }
+        mins.push(minQueue.front().map_or(f64::NAN, |j| values[*j]));
+        maxs.push(maxQueue.front().map_or(f64::NAN, |j| values[*j]));
+    }
+
+    RollingStatsStruct {
+        x: (0..values.len()).map(|i| i as f64).collect(),
+        lowerBand: means
+            .iter()
+            .zip(stds.iter())
+            .map(|(m, s)| m - k * s)
+            .collect(),
+        upperBand: means
+            .iter()
+            .zip(stds.iter())
+            .map(|(m, s)| m + k * s)
+            .collect(),
+        mean: means,
+        std: stds,
+        min: mins,
+        max: maxs,
+    }
+}
+
+/**
+ * Get rolling statistics of every group
+ * @method groupsRollingStats
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @returns {array} - empty when rollingWindow is not set
+ */
+pub fn groupsRollingStats(
+    groups: Vec<GroupItemStruct>,
+    config: &ConfigStruct,
+) -> Vec<RollingStatsStruct> {
+    let window = match config.rollingWindow {
+        Some(window) => window as usize,
+        None => return vec![],
+    };
+    let k = config.rollingK.unwrap_or(DEFAULT_ROLLING_K);
+
+    groups
+        .into_iter()
+        .map(|group| RollingStatsStruct {
+            x: groupXValues(&group),
+            ..rollingStatistics(&group.values.unwrap_or(vec![]), window, k)
+        })
+        .collect()
+}
@@ -0,0 +1,301 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{statistics::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * One-way analysis of variance of the means
+ * @method oneWayAnova
+ * @param {array} samples - values of every group
+ * @returns {object}
+ */
+pub fn oneWayAnova(samples: &[Vec<f64>]) -> HypothesisTestStruct {
+    let k = samples.len() as f64;
+    let total = samples.iter().map(|s| s.len()).sum::<usize>() as f64;
+    let grandMean = samples.iter().flatten().sum::<f64>() / total;
+
+    let mut ssBetween = 0.0;
+    let mut ssWithin = 0.0;
+
+    for sample in samples.iter() {
+        let m = mean(sample);
+
+        ssBetween += sample.len() as f64 * (m - grandMean).powi(2);
+        ssWithin += sample.iter().map(|v| (v - m).powi(2)).sum::<f64>();
+    }
+
+    let df1 = k - 1.0;
+    let df2 = total - k;
+    let statistic = (ssBetween / df1) / (ssWithin / df2);
+
+    HypothesisTestStruct {
+        statistic,
+        df1,
+        df2: Some(df2),
+        pValue: fPValue(statistic, df1, df2),
+    }
+}
+
+/**
+ * Kruskal-Wallis rank test, with tie correction
+ * @method kruskalWallis
+ * @param {array} samples - values of every group
+ * @returns {object}
+ */
+pub fn kruskalWallis(samples: &[Vec<f64>]) -> HypothesisTestStruct {
+    let pooled: Vec<f64> = samples.iter().flatten().copied().collect();
+    let pooledRanks = ranks(&pooled);
+    let total = pooled.len() as f64;
+
+    let mut offset = 0;
+    let mut rankSum = 0.0;
+
+    for sample in samples.iter() {
+        let sampleRankSum: f64 = pooledRanks[offset..offset + sample.len()].iter().sum();
+
+        rankSum += sampleRankSum.powi(2) / sample.len() as f64;
+        offset += sample.len();
+    }
+
+    let h = 12.0 / (total * (total + 1.0)) * rankSum - 3.0 * (total + 1.0);
+
+    // Tie correction, every group of ties of size t contributes t^3 - t
+    let mut sortedRanks = pooledRanks.clone();
+    sortedRanks.sort_by(|a, b| a.total_cmp(b));
+
+    let mut ties = 0.0;
+    let mut start = 0;
+
+    while start < sortedRanks.len() {
+        let mut end = start;
+
+        while end + 1 < sortedRanks.len() && sortedRanks[end + 1] == sortedRanks[start] {
+            end += 1;
+        }
+
+        let t = (end - start + 1) as f64;
+        ties += t.powi(3) - t;
+        start = end + 1;
+    }
+
+    let correction = 1.0 - ties / (total.powi(3) - total);
+    let statistic = if correction > 0.0 {
+        h / correction
+    } else {
+        0.0
+    };
+    let df1 = samples.len() as f64 - 1.0;
+
+    HypothesisTestStruct {
+        statistic,
+        df1,
+        df2: None,
+        pValue: 1.0 - chiSquareCdf(statistic, df1),
+    }
+}
+
+/**
+ * Levene's test for equal variances, centred on the group medians (Brown-Forsythe)
+ * @method leveneTest
+ * @param {array} samples - values of every group
+ * @returns {object}
+ */
+pub fn leveneTest(samples: &[Vec<f64>]) -> HypothesisTestStruct {
+    let deviations: Vec<Vec<f64>> = samples
+        .iter()
+        .map(|sample| {
+            let center = median(&sortedValues(sample));
+
+            sample.iter().map(|v| (v - center).abs()).collect()
+        })
+        .collect();
+
+    oneWayAnova(&deviations)
+}
+
+/**
+ * Welch's unequal variances t-test between two samples
+ * @method welchTTest
+ * @param {array} a
+ * @param {array} b
+ * @returns {object}
+ */
+pub fn welchTTest(a: &[f64], b: &[f64]) -> HypothesisTestStruct {
+    let na = a.len() as f64;
+    let nb = b.len() as f64;
+    let va = variance(a) / na;
+    let vb = variance(b) / nb;
+    let delta = mean(a) - mean(b);
+
+    // Both samples are constant, the means are either equal or certainly different
+    if va + vb == 0.0 {
+        return HypothesisTestStruct {
+            statistic: if delta == 0.0 { 0.0 } else { f64::INFINITY },
+            df1: na + nb - 2.0,
+            df2: None,
+            pValue: if delta == 0.0 { 1.0 } else { 0.0 },
+        };
+    }
+
+    let statistic = delta / (va + vb).sqrt();
+    let df1 = (va + vb).powi(2) / (va.powi(2) / (na - 1.0) + vb.powi(2) / (nb - 1.0));
+
+    HypothesisTestStruct {
+        statistic,
+        df1,
+        df2: None,
+        pValue: 2.0 * (1.0 - studentTCdf(statistic.abs(), df1)),
+    }
+}
+
+/**
+ * Get upper tail probability of F distribution, NaN statistic gives 1
+ * @method fPValue
+ * @param {number} statistic
+ * @param {number} df1
+ * @param {number} df2
+ * @returns {number}
+ */
+fn fPValue(statistic: f64, df1: f64, df2: f64) -> f64 {
+    if statistic.is_nan() {
+        return 1.0;
+    }
+    if statistic.is_infinite() {
+        return 0.0;
+    }
+
+    1.0 - fCdf(statistic, df1, df2)
+}
+
+/**
+ * Adjust p-values for multiple comparisons
+ * @method adjustPValues
+ * @param {array} pValues
+ * @param {string} correction - "holm", "bonferroni" or "none"
+ * @returns {array} - NaN stays NaN and isn't counted, it is never below alpha
+ */
+pub fn adjustPValues(pValues: &[f64], correction: &str) -> Vec<f64> {
+    // A NaN p-value, from an overflowing variance, is no test and takes no step
+    let m = pValues.iter().filter(|p| !p.is_nan()).count() as f64;
+
+    match correction {
+        "bonferroni" => pValues
+            .iter()
+            .map(|p| if p.is_nan() { *p } else { (p * m).min(1.0) })
+            .collect(),
+        "holm" => {
+            let mut order: Vec<usize> = (0..pValues.len())
+                .filter(|i| !pValues[*i].is_nan())
+                .collect();
+            order.sort_by(|a, b| pValues[*a].total_cmp(&pValues[*b]));
+
+            let mut adjusted = vec![f64::NAN; pValues.len()];
+            let mut running: f64 = 0.0;
+
+            // Step-down, adjusted values must stay monotonic
+            for (rank, index) in order.into_iter().enumerate() {
+                running = running.max((pValues[index] * (m - rank as f64)).min(1.0));
+                adjusted[index] = running;
+            }
+
+            adjusted
+        }
+        _ => pValues.to_vec(),
+    }
+}
+
+/**
+ * Compare visible groups against each other
+ * @method siteComparison
+ * @param {array} groups - groups returned by filterGroup
+ * @param {number} alpha - significance level
+ * @param {string} correction - "holm", "bonferroni" or "none"
+ * @returns {object} - null when less than 2 groups have at least 2 values
+ */
+pub fn siteComparison(
+    groups: Vec<GroupItemStruct>,
+    alpha: f64,
+    correction: String,
+) -> Option<SiteComparisonStruct> {
+    let mut keyValues: Vec<KeyValuesStruct> = Vec::new();
+    let mut samples: Vec<Vec<f64>> = Vec::new();
+
+    // Keep only groups that carry a variance estimate
+    for item in groups.into_iter() {
+        let values: Vec<f64> = item
+            .values
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter(|v| !v.is_nan())
+            .collect();
+
+        if values.len() >= 2 {
+            keyValues.push(item.keyValues.unwrap_or(KeyValuesStruct { SITE_NUM: None }));
+            samples.push(values);
+        }
+    }
+
+    let k = samples.len();
+
+    if k < 2 {
+        return None;
+    }
+
+    // Pairwise Welch tests on the upper triangle
+    let mut meanDeltas = vec![vec![0.0; k]; k];
+    let mut pairs: Vec<(usize, usize)> = Vec::new();
+    let mut pairPValues: Vec<f64> = Vec::new();
+
+    for i in 0..k {
+        for j in (i + 1)..k {
+            let delta = mean(&samples[i]) - mean(&samples[j]);
+
+            meanDeltas[i][j] = delta;
+            meanDeltas[j][i] = -delta;
+
+            pairs.push((i, j));
+            pairPValues.push(welchTTest(&samples[i], &samples[j]).pValue);
+        }
+    }
+
+    let mut pValues = vec![vec![1.0; k]; k];
+
+    for ((i, j), p) in pairs
+        .into_iter()
+        .zip(adjustPValues(&pairPValues, &correction))
+    {
+        pValues[i][j] = p;
+        pValues[j][i] = p;
+    }
+
+    // Every site against the pooled values of all other sites
+    let restPValues: Vec<f64> = (0..k)
+        .map(|i| {
+            let rest: Vec<f64> = samples
+                .iter()
+                .enumerate()
+                .filter(|(j, _)| *j != i)
+                .flat_map(|(_, sample)| sample.iter().copied())
+                .collect();
+
+            welchTTest(&samples[i], &rest).pValue
+        })
+        .collect();
+
+    let restPValues = adjustPValues(&restPValues, &correction);
+    let differentSites = restPValues.iter().map(|p| *p < alpha).collect();
+
+    Some(SiteComparisonStruct {
+        keyValues,
+        anova: oneWayAnova(&samples),
+        kruskalWallis: kruskalWallis(&samples),
+        levene: leveneTest(&samples),
+        meanDeltas,
+        pValues,
+        restPValues,
+        differentSites,
+    })
+}
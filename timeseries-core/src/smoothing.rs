@@ -0,0 +1,215 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*, timestamps::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Trailing simple moving average, first values average what is available
+ * @method simpleMovingAverage
+ * @param {array} values
+ * @param {number} window
+ * @returns {array}
+ */
+pub fn simpleMovingAverage(values: &[f64], window: usize) -> Vec<f64> {
+    let mut returnedValues: Vec<f64> = Vec::new();
+    let mut sum = 0.0;
+
+    for (i, value) in values.iter().enumerate() {
+        sum += value;
+
+        if i >= window {
+            sum -= values[i - window];
+        }
+
+        returnedValues.push(sum / (i + 1).min(window) as f64);
+    }
+
+    returnedValues
+}
+
+/**
+ * Exponential moving average with alpha = 2 / (window + 1)
+ * @method exponentialMovingAverage
+ * @param {array} values
+ * @param {number} window
+ * @returns {array}
+ */
+pub fn exponentialMovingAverage(values: &[f64], window: usize) -> Vec<f64> {
+    let alpha = 2.0 / (window as f64 + 1.0);
+    let mut returnedValues: Vec<f64> = Vec::new();
+
+    for (i, value) in values.iter().enumerate() {
+        let ema = if i == 0 {
+            *value
+        } else {
+            alpha * value + (1.0 - alpha) * returnedValues[i - 1]
+        };
+
+        returnedValues.push(ema);
+    }
+
+    returnedValues
+}
+
+/**
+ * Get bounds of the centred window around index, clipped to values
+ * @method centredWindow
+ * @param {number} index
+ * @param {number} length - number of values
+ * @param {number} window
+ * @returns {array} - [start, end)
+ */
+fn centredWindow(index: usize, length: usize, window: usize) -> (usize, usize) {
+    let half = window / 2;
+
+    (index.saturating_sub(half), (index + half + 1).min(length))
+}
+
+/**
+ * Centred rolling median, window shrinks at both ends
+ * @method rollingMedian
+ * @param {array} values
+ * @param {number} window
+ * @returns {array}
+ */
+pub fn rollingMedian(values: &[f64], window: usize) -> Vec<f64> {
+    (0..values.len())
+        .map(|i| {
+            let (start, end) = centredWindow(i, values.len(), window);
+
+            median(&sortedValues(&values[start..end]))
+        })
+        .collect()
+}
+
+/**
+ * Savitzky-Golay filter, local polynomial least squares over the centred window
+ * @method savitzkyGolay
+ * @param {array} values
+ * @param {number} window
+ * @param {number} polyOrder
+ * @returns {array}
+ */
+pub fn savitzkyGolay(values: &[f64], window: usize, polyOrder: usize) -> Vec<f64> {
+    (0..values.len())
+        .map(|i| {
+            let (start, end) = centredWindow(i, values.len(), window);
+
+            // x is centred on i so the fitted value is the constant term
+            let x: Vec<f64> = (start..end).map(|j| j as f64 - i as f64).collect();
+            let weights = vec![1.0; x.len()];
+            let degree = polyOrder.min(x.len() - 1);
+
+            polynomialFit(&x, &values[start..end], &weights, degree)
+                .map(|coefficients| coefficients[0])
+                .unwrap_or(values[i])
+        })
+        .collect()
+}
+
+/**
+ * LOESS, local linear regression on the window nearest values with tricube weights
+ * @method loess
+ * @param {array} values
+ * @param {number} window - number of neighbours used by every local fit
+ * @returns {array}
+ */
+pub fn loess(values: &[f64], window: usize) -> Vec<f64> {
+    let n = values.len();
+    let span = window.clamp(2, n.max(2));
+
+    (0..n)
+        .map(|i| {
+            // Nearest neighbours on the index axis
+            let start = i.saturating_sub(span / 2).min(n.saturating_sub(span));
+            let end = (start + span).min(n);
+
+            let maxDistance = (start..end)
+                .map(|j| (j as f64 - i as f64).abs())
+                .fold(1.0, f64::max)
+                * 1.0001;
+
+            let x: Vec<f64> = (start..end).map(|j| j as f64 - i as f64).collect();
+            let weights: Vec<f64> = x
+                .iter()
+                .map(|d| (1.0 - (d.abs() / maxDistance).powi(3)).powi(3))
+                .collect();
+
+            polynomialFit(&x, &values[start..end], &weights, 1.min(x.len() - 1))
+                .map(|coefficients| coefficients[0])
+                .unwrap_or(values[i])
+        })
+        .collect()
+}
+
+/**
+ * Get smoothing series of values with the selected method
+ * @method smoothValues
+ * @param {array} values
+ * @param {string} method - "sma", "ema", "median", "savgol" or "loess"
+ * @param {number} window
+ * @param {number} polyOrder - only for "savgol"
+ * @returns {array} - null when method is unknown
+ */
+pub fn smoothValues(
+    values: &[f64],
+    method: &str,
+    window: usize,
+    polyOrder: usize,
+) -> Option<Vec<f64>> {
+    match method {
+        "sma" => Some(simpleMovingAverage(values, window)),
+        "ema" => Some(exponentialMovingAverage(values, window)),
+        "median" => Some(rollingMedian(values, window)),
+        "savgol" => Some(savitzkyGolay(values, window, polyOrder)),
+        "loess" => Some(loess(values, window)),
+        _ => None,
+    }
+}
+
+/**
+ * Get smoothing overlay series of every group
+ * @method groupsSmoothing
+ * @param {array} groups - groups returned by filterGroup
+ * @param {object} config
+ * @returns {array} - series per group, x matches preprocess
+ */
+pub fn groupsSmoothing(
+    groups: Vec<GroupItemStruct>,
+    config: &ConfigStruct,
+) -> Vec<Vec<SeriesStruct>> {
+    let methods = config.smoothing.clone().unwrap_or(vec![]);
+    let window = config
+        .smoothingWindow
+        .unwrap_or(DEFAULT_SMOOTHING_WINDOW)
+        .max(1) as usize;
+    let polyOrder = config
+        .smoothingPolyOrder
+        .unwrap_or(DEFAULT_SMOOTHING_POLY_ORDER) as usize;
+
+    if methods.is_empty() {
+        return vec![];
+    }
+
+    groups
+        .into_iter()
+        .map(|group| {
+            let xValues = groupXValues(&group);
+            let values = group.values.unwrap_or(vec![]);
+
+            methods
+                .iter()
+                .filter_map(|method| {
+                    smoothValues(&values, method, window, polyOrder).map(|y| SeriesStruct {
+                        name: method.clone(),
+                        x: xValues.clone(),
+                        y,
+                    })
+                })
+                .collect()
+        })
+        .collect()
+}
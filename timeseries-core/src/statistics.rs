@@ -0,0 +1,527 @@
+#![allow(non_snake_case)]
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Sort values ascending, NaN values are dropped
+ * @method sortedValues
+ * @param {array} values
+ * @returns {array}
+ */
+pub fn sortedValues(values: &[f64]) -> Vec<f64> {
+    let mut sorted: Vec<f64> = values.iter().copied().filter(|v| !v.is_nan()).collect();
+
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    sorted
+}
+
+/**
+ * Get quantile of sorted values with linear interpolation between closest ranks
+ * @method quantile
+ * @param {array} sorted - values sorted ascending
+ * @param {number} p - probability in [0, 1]
+ * @returns {number} - NaN when values is empty
+ */
+pub fn quantile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return f64::NAN;
+    }
+
+    let p = p.clamp(0.0, 1.0);
+    let position = (sorted.len() - 1) as f64 * p;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (position - lower as f64)
+}
+
+/**
+ * Get median of sorted values
+ * @method median
+ * @param {array} sorted - values sorted ascending
+ * @returns {number}
+ */
+pub fn median(sorted: &[f64]) -> f64 {
+    quantile(sorted, 0.5)
+}
+
+/**
+ * Get arithmetic mean of values
+ * @method mean
+ * @param {array} values
+ * @returns {number} - NaN when values is empty
+ */
+pub fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return f64::NAN;
+    }
+
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+/**
+ * Get sample variance of values (n - 1 denominator)
+ * @method variance
+ * @param {array} values
+ * @returns {number} - NaN when values has less than 2 items
+ */
+pub fn variance(values: &[f64]) -> f64 {
+    if values.len() < 2 {
+        return f64::NAN;
+    }
+
+    let m = mean(values);
+
+    values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+}
+
+/**
+ * Get sample standard deviation of values
+ * @method standardDeviation
+ * @param {array} values
+ * @returns {number}
+ */
+pub fn standardDeviation(values: &[f64]) -> f64 {
+    variance(values).sqrt()
+}
+
+/**
+ * Get natural logarithm of gamma function (Lanczos approximation)
+ * @method lnGamma
+ * @param {number} x - x > 0
+ * @returns {number}
+ */
+pub fn lnGamma(x: f64) -> f64 {
+    const COEFFICIENTS: [f64; 6] = [
+        76.18009172947146,
+        -86.50532032941677,
+        24.01409824083091,
+        -1.231739572450155,
+        0.1208650973866179e-2,
+        -0.5395239384953e-5,
+    ];
+
+    let mut y = x;
+    let tmp = x + 5.5 - (x + 0.5) * (x + 5.5).ln();
+    let mut series = 1.000000000190015;
+
+    for coefficient in COEFFICIENTS.iter() {
+        y += 1.0;
+        series += coefficient / y;
+    }
+
+    -tmp + (2.5066282746310005 * series / x).ln()
+}
+
+/**
+ * Continued fraction used by regularizedIncompleteBeta
+ * @method betaContinuedFraction
+ * @param {number} a
+ * @param {number} b
+ * @param {number} x
+ * @returns {number}
+ */
+fn betaContinuedFraction(a: f64, b: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 300;
+    const EPSILON: f64 = 3.0e-14;
+    const TINY: f64 = 1.0e-300;
+
+    let qab = a + b;
+    let qap = a + 1.0;
+    let qam = a - 1.0;
+    let mut c = 1.0;
+    let mut d = 1.0 - qab * x / qap;
+
+    if d.abs() < TINY {
+        d = TINY;
+    }
+
+    d = 1.0 / d;
+    let mut h = d;
+
+    for m in 1..=MAX_ITERATIONS {
+        let m = m as f64;
+        let m2 = 2.0 * m;
+
+        // Even step of the recurrence
+        let aa = m * (b - m) * x / ((qam + m2) * (a + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        h *= d * c;
+
+        // Odd step of the recurrence
+        let aa = -(a + m) * (qab + m) * x / ((a + m2) * (qap + m2));
+        d = 1.0 + aa * d;
+        if d.abs() < TINY {
+            d = TINY;
+        }
+        c = 1.0 + aa / c;
+        if c.abs() < TINY {
+            c = TINY;
+        }
+        d = 1.0 / d;
+        let delta = d * c;
+        h *= delta;
+
+        if (delta - 1.0).abs() < EPSILON {
+            break;
+        }
+    }
+
+    h
+}
+
+/**
+ * Get regularized incomplete beta function I_x(a, b)
+ * @method regularizedIncompleteBeta
+ * @param {number} a
+ * @param {number} b
+ * @param {number} x - x in [0, 1]
+ * @returns {number}
+ */
+pub fn regularizedIncompleteBeta(a: f64, b: f64, x: f64) -> f64 {
+    if x <= 0.0 {
+        return 0.0;
+    }
+    if x >= 1.0 {
+        return 1.0;
+    }
+
+    let front = (lnGamma(a + b) - lnGamma(a) - lnGamma(b) + a * x.ln() + b * (1.0 - x).ln()).exp();
+
+    if x < (a + 1.0) / (a + b + 2.0) {
+        front * betaContinuedFraction(a, b, x) / a
+    } else {
+        1.0 - front * betaContinuedFraction(b, a, 1.0 - x) / b
+    }
+}
+
+/**
+ * Get regularized lower incomplete gamma function P(a, x)
+ * @method regularizedIncompleteGamma
+ * @param {number} a
+ * @param {number} x
+ * @returns {number}
+ */
+pub fn regularizedIncompleteGamma(a: f64, x: f64) -> f64 {
+    const MAX_ITERATIONS: usize = 300;
+    const EPSILON: f64 = 3.0e-14;
+    const TINY: f64 = 1.0e-300;
+
+    if x <= 0.0 {
+        return 0.0;
+    }
+
+    let gln = lnGamma(a);
+
+    if x < a + 1.0 {
+        // Series representation
+        let mut ap = a;
+        let mut delta = 1.0 / a;
+        let mut sum = delta;
+
+        for _ in 0..MAX_ITERATIONS {
+            ap += 1.0;
+            delta *= x / ap;
+            sum += delta;
+
+            if delta.abs() < sum.abs() * EPSILON {
+                break;
+            }
+        }
+
+        sum * (-x + a * x.ln() - gln).exp()
+    } else {
+        // Continued fraction representation
+        let mut b = x + 1.0 - a;
+        let mut c = 1.0 / TINY;
+        let mut d = 1.0 / b;
+        let mut h = d;
+
+        for i in 1..=MAX_ITERATIONS {
+            let an = -(i as f64) * (i as f64 - a);
+            b += 2.0;
+            d = an * d + b;
+            if d.abs() < TINY {
+                d = TINY;
+            }
+            c = b + an / c;
+            if c.abs() < TINY {
+                c = TINY;
+            }
+            d = 1.0 / d;
+            let delta = d * c;
+            h *= delta;
+
+            if (delta - 1.0).abs() < EPSILON {
+                break;
+            }
+        }
+
+        1.0 - (-x + a * x.ln() - gln).exp() * h
+    }
+}
+
+/**
+ * Get cumulative distribution of Student's t distribution
+ * @method studentTCdf
+ * @param {number} t
+ * @param {number} df - degrees of freedom
+ * @returns {number}
+ */
+pub fn studentTCdf(t: f64, df: f64) -> f64 {
+    let tail = 0.5 * regularizedIncompleteBeta(df / 2.0, 0.5, df / (df + t * t));
+
+    if t > 0.0 {
+        1.0 - tail
+    } else {
+        tail
+    }
+}
+
+/**
+ * Get quantile (inverse cumulative distribution) of Student's t distribution
+ * @method studentTQuantile
+ * @param {number} p - probability in (0, 1)
+ * @param {number} df - degrees of freedom
+ * @returns {number}
+ */
+pub fn studentTQuantile(p: f64, df: f64) -> f64 {
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    // Expand bracket until it contains the quantile, then bisect
+    let mut low = -1.0;
+    let mut high = 1.0;
+
+    while studentTCdf(low, df) > p {
+        low *= 2.0;
+    }
+    while studentTCdf(high, df) < p {
+        high *= 2.0;
+    }
+
+    for _ in 0..200 {
+        let middle = 0.5 * (low + high);
+
+        if studentTCdf(middle, df) < p {
+            low = middle;
+        } else {
+            high = middle;
+        }
+
+        if high - low < 1.0e-12 * (1.0 + middle.abs()) {
+            break;
+        }
+    }
+
+    0.5 * (low + high)
+}
+
+/**
+ * Get cumulative distribution of F distribution
+ * @method fCdf
+ * @param {number} f
+ * @param {number} df1 - numerator degrees of freedom
+ * @param {number} df2 - denominator degrees of freedom
+ * @returns {number}
+ */
+pub fn fCdf(f: f64, df1: f64, df2: f64) -> f64 {
+    if f <= 0.0 {
+        return 0.0;
+    }
+
+    regularizedIncompleteBeta(df1 / 2.0, df2 / 2.0, df1 * f / (df1 * f + df2))
+}
+
+/**
+ * Get cumulative distribution of chi-square distribution
+ * @method chiSquareCdf
+ * @param {number} x
+ * @param {number} df - degrees of freedom
+ * @returns {number}
+ */
+pub fn chiSquareCdf(x: f64, df: f64) -> f64 {
+    regularizedIncompleteGamma(df / 2.0, x / 2.0)
+}
+
+/**
+ * Get cumulative distribution of standard normal distribution
+ * @method normalCdf
+ * @param {number} z
+ * @returns {number}
+ */
+pub fn normalCdf(z: f64) -> f64 {
+    let half = 0.5 * regularizedIncompleteGamma(0.5, z * z / 2.0);
+
+    if z >= 0.0 {
+        0.5 + half
+    } else {
+        0.5 - half
+    }
+}
+
+/**
+ * Get quantile (inverse cumulative distribution) of standard normal distribution
+ * @method normalQuantile
+ * @param {number} p - probability in (0, 1)
+ * @returns {number}
+ */
+pub fn normalQuantile(p: f64) -> f64 {
+    if p <= 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p >= 1.0 {
+        return f64::INFINITY;
+    }
+
+    let mut low = -40.0;
+    let mut high = 40.0;
+
+    for _ in 0..200 {
+        let middle = 0.5 * (low + high);
+
+        if normalCdf(middle) < p {
+            low = middle;
+        } else {
+            high = middle;
+        }
+
+        if high - low < 1.0e-12 {
+            break;
+        }
+    }
+
+    0.5 * (low + high)
+}
+
+/**
+ * Get ranks of values, ties get the average of their ranks
+ * @method ranks
+ * @param {array} values
+ * @returns {array} - 1-based rank per value, NaN ranks after every number
+ */
+pub fn ranks(values: &[f64]) -> Vec<f64> {
+    let mut order: Vec<usize> = (0..values.len()).collect();
+    order.sort_by(|a, b| values[*a].total_cmp(&values[*b]));
+
+    let mut returnedRanks = vec![0.0; values.len()];
+    let mut start = 0;
+
+    while start < order.len() {
+        let mut end = start;
+
+        while end + 1 < order.len() && values[order[end + 1]] == values[order[start]] {
+            end += 1;
+        }
+
+        let averageRank = (start + end) as f64 / 2.0 + 1.0;
+
+        for index in order.iter().take(end + 1).skip(start) {
+            returnedRanks[*index] = averageRank;
+        }
+
+        start = end + 1;
+    }
+
+    returnedRanks
+}
+
+/**
+ * Solve linear system by Gaussian elimination with partial pivoting
+ * @method solveLinearSystem
+ * @param {array} matrix - square matrix, row major
+ * @param {array} rhs - right hand side
+ * @returns {array} - null when matrix is singular
+ */
+pub fn solveLinearSystem(matrix: Vec<Vec<f64>>, rhs: Vec<f64>) -> Option<Vec<f64>> {
+    let n = rhs.len();
+    let mut a = matrix;
+    let mut b = rhs;
+
+    for column in 0..n {
+        let pivot =
+            (column..n).max_by(|i, j| a[*i][column].abs().total_cmp(&a[*j][column].abs()))?;
+
+        if a[pivot][column].is_nan() || a[pivot][column].abs() < 1.0e-12 {
+            return None;
+        }
+
+        a.swap(column, pivot);
+        b.swap(column, pivot);
+
+        let pivotRow = a[column].clone();
+
+        for row in (column + 1)..n {
+            let factor = a[row][column] / pivotRow[column];
+
+            for (value, pivotValue) in a[row].iter_mut().zip(pivotRow.iter()).skip(column) {
+                *value -= factor * pivotValue;
+            }
+
+            b[row] -= factor * b[column];
+        }
+    }
+
+    let mut solution = vec![0.0; n];
+
+    for row in (0..n).rev() {
+        let sum: f64 = ((row + 1)..n).map(|k| a[row][k] * solution[k]).sum();
+
+        solution[row] = (b[row] - sum) / a[row][row];
+    }
+
+    Some(solution)
+}
+
+/**
+ * Fit polynomial by weighted least squares
+ * @method polynomialFit
+ * @param {array} x
+ * @param {array} y
+ * @param {array} weights
+ * @param {number} degree
+ * @returns {array} - coefficients from constant term upwards, null when fit is singular
+ */
+pub fn polynomialFit(x: &[f64], y: &[f64], weights: &[f64], degree: usize) -> Option<Vec<f64>> {
+    let size = degree + 1;
+    let mut normal = vec![vec![0.0; size]; size];
+    let mut rhs = vec![0.0; size];
+
+    for ((xi, yi), wi) in x.iter().zip(y.iter()).zip(weights.iter()) {
+        let powers: Vec<f64> = (0..size).map(|p| xi.powi(p as i32)).collect();
+
+        for row in 0..size {
+            for column in 0..size {
+                normal[row][column] += wi * powers[row] * powers[column];
+            }
+
+            rhs[row] += wi * powers[row] * yi;
+        }
+    }
+
+    solveLinearSystem(normal, rhs)
+}
+
+/**
+ * Evaluate polynomial at x
+ * @method polynomialValue
+ * @param {array} coefficients - from constant term upwards
+ * @param {number} x
+ * @returns {number}
+ */
+pub fn polynomialValue(coefficients: &[f64], x: f64) -> f64 {
+    coefficients.iter().rev().fold(0.0, |acc, c| acc * x + c)
+}
@@ -0,0 +1,414 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use serde::{Deserialize, Serialize};
+
+/* -------------- Define structs -------------- */
+
+#[derive(Default, Serialize, Deserialize)]
+pub struct ConfigStruct {
+    // common config
+    pub valueRangeMode: Option<String>,
+    pub hiddenGroups: Option<Vec<u32>>,
+    pub limitHighlight: bool,
+    pub xMode: Option<String>,
+    pub displayUnit: Option<String>,
+    pub palette: Option<String>,
+
+    // only timeseries
+    pub drawLines: Option<bool>,
+    pub marker: Option<bool>,
+    pub markerSize: Option<u32>,
+    pub maxPoints: Option<u32>,
+
+    // only box plot
+    pub whiskerMode: Option<String>,
+    pub whiskerPercentiles: Option<[f64; 2]>,
+
+    // outlier detection
+    pub outlierMethod: Option<String>,
+    pub outlierThreshold: Option<f64>,
+    pub outlierAlpha: Option<f64>,
+    pub outlierMaxCount: Option<u32>,
+    pub excludeOutliers: Option<bool>,
+
+    // site-to-site comparison
+    pub comparisonAlpha: Option<f64>,
+    pub comparisonCorrection: Option<String>,
+
+    // change-point detection
+    pub changePointMethod: Option<String>,
+    pub changePointCost: Option<String>,
+    pub changePointPenalty: Option<f64>,
+    pub changePointMaxCount: Option<u32>,
+    pub changePointMinSize: Option<u32>,
+
+    // EWMA and CUSUM control charts
+    pub controlCharts: Option<bool>,
+    pub controlBaseline: Option<u32>,
+    pub ewmaLambda: Option<f64>,
+    pub ewmaL: Option<f64>,
+    pub cusumK: Option<f64>,
+    pub cusumH: Option<f64>,
+
+    // smoothing overlays
+    pub smoothing: Option<Vec<String>>,
+    pub smoothingWindow: Option<u32>,
+    pub smoothingPolyOrder: Option<u32>,
+
+    // rolling statistics bands
+    pub rollingWindow: Option<u32>,
+    pub rollingK: Option<f64>,
+
+    // time resampling
+    pub resampleBucket: Option<String>,
+    pub resampleAggregation: Option<String>,
+    pub timezoneOffset: Option<f64>,
+    pub shiftHours: Option<f64>,
+    pub shiftStartHour: Option<f64>,
+
+    // autocorrelation and spectrum
+    pub autocorrelationMaxLag: Option<u32>,
+    pub autocorrelationAlpha: Option<f64>,
+
+    // forecasting
+    pub forecastHorizon: Option<u32>,
+    pub forecastModel: Option<String>,
+    pub holtAlpha: Option<f64>,
+    pub holtBeta: Option<f64>,
+    pub arOrder: Option<u32>,
+
+    // seasonal-trend decomposition
+    pub stlPeriod: Option<u32>,
+    pub stlSeasonalWindow: Option<u32>,
+    pub stlRobustIterations: Option<u32>,
+
+    // stats validation
+    pub statsTolerance: Option<f64>,
+
+    // tooltip
+    pub tooltipFields: Option<Vec<String>>,
+
+    // csv import
+    pub csvFormat: Option<String>,
+    pub csvDelimiter: Option<String>,
+    pub csvDecimalComma: Option<bool>,
+    pub csvKeyColumns: Option<Vec<String>>,
+    pub csvValueColumn: Option<String>,
+    pub csvTimestampColumn: Option<String>,
+    pub csvHiLimit: Option<f64>,
+    pub csvLoLimit: Option<f64>,
+    pub csvUnits: Option<String>,
+
+    // export
+    pub width: Option<u32>,
+    pub height: Option<u32>,
+    pub dpi: Option<f64>,
+}
+
+#[derive(Clone, Copy, Debug, Serialize, Deserialize, PartialEq)]
+pub struct KeyValuesStruct {
+    pub SITE_NUM: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct StatsGroupStruct {
+    pub Count: Option<u32>,
+    pub Cp: Option<String>,
+    pub Cpk: Option<String>,
+    pub Max: Option<String>,
+    pub Mean: Option<String>,
+    pub Min: Option<String>,
+    pub Std: Option<String>,
+    pub cp: Option<f64>,
+    pub cpk: Option<f64>,
+    pub max: Option<f64>,
+    pub mean: Option<f64>,
+    pub min: Option<f64>,
+    pub std: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+#[serde(untagged)]
+pub enum TimestampEnum {
+    Epoch(f64),
+    Iso(String),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct GroupItemStruct {
+    pub keyValues: Option<KeyValuesStruct>,
+    pub color: Option<String>,
+    pub stats: Option<StatsGroupStruct>,
+    pub values: Option<Vec<f64>>,
+    pub timestamps: Option<Vec<TimestampEnum>>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct StatsStruct {
+    pub count: Option<u32>,
+    pub cp: Option<f64>,
+    pub cpk: Option<f64>,
+    pub max: Option<f64>,
+    pub mean: Option<f64>,
+    pub min: Option<f64>,
+    pub std: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct InfoStruct {
+    pub HI_LIMIT: Option<f64>,
+    pub LO_LIMIT: Option<f64>,
+    pub UNITS: Option<String>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DataStruct {
+    pub groups: Option<Vec<GroupItemStruct>>,
+    pub stats: Option<StatsStruct>,
+    pub info: Option<InfoStruct>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct PreprocessItemStruct {
+    pub color: String,
+    pub keyValues: KeyValuesStruct,
+    pub stats: StatsGroupStruct,
+    pub value: f64,
+    pub x: f64,
+    pub y: f64,
+    pub outlier: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct OutlierPointStruct {
+    pub x: f64,
+    pub value: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct BoxPlotStruct {
+    pub keyValues: KeyValuesStruct,
+    pub color: String,
+    pub count: u32,
+    pub min: f64,
+    pub q1: f64,
+    pub median: f64,
+    pub q3: f64,
+    pub max: f64,
+    pub iqr: f64,
+    pub lowerWhisker: f64,
+    pub upperWhisker: f64,
+    pub outliers: Vec<OutlierPointStruct>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct HypothesisTestStruct {
+    pub statistic: f64,
+    pub df1: f64,
+    pub df2: Option<f64>,
+    pub pValue: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SiteComparisonStruct {
+    pub keyValues: Vec<KeyValuesStruct>,
+    pub anova: HypothesisTestStruct,
+    pub kruskalWallis: HypothesisTestStruct,
+    pub levene: HypothesisTestStruct,
+    pub meanDeltas: Vec<Vec<f64>>,
+    pub pValues: Vec<Vec<f64>>,
+    pub restPValues: Vec<f64>,
+    pub differentSites: Vec<bool>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SegmentStruct {
+    pub start: u32,
+    pub end: u32,
+    pub count: u32,
+    pub mean: f64,
+    pub std: f64,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ChangePointsStruct {
+    pub changePoints: Vec<u32>,
+    pub segments: Vec<SegmentStruct>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct EwmaStruct {
+    pub x: Vec<f64>,
+    pub statistic: Vec<f64>,
+    pub center: f64,
+    pub lowerLimit: Vec<f64>,
+    pub upperLimit: Vec<f64>,
+    pub firstOutOfControl: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct CusumStruct {
+    pub x: Vec<f64>,
+    pub upperStatistic: Vec<f64>,
+    pub lowerStatistic: Vec<f64>,
+    pub limit: Vec<f64>,
+    pub firstOutOfControl: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SeriesStruct {
+    pub name: String,
+    pub x: Vec<f64>,
+    pub y: Vec<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct RollingStatsStruct {
+    pub x: Vec<f64>,
+    pub mean: Vec<f64>,
+    pub std: Vec<f64>,
+    pub min: Vec<f64>,
+    pub max: Vec<f64>,
+    pub lowerBand: Vec<f64>,
+    pub upperBand: Vec<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct AutocorrelationStruct {
+    pub lags: Vec<u32>,
+    pub acf: Vec<f64>,
+    pub acfBounds: Vec<f64>,
+    pub pacf: Vec<f64>,
+    pub pacfBound: f64,
+    pub frequencies: Vec<f64>,
+    pub periodogram: Vec<f64>,
+    pub dominantPeriod: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct ForecastStruct {
+    pub model: String,
+    pub x: Vec<f64>,
+    pub mean: Vec<f64>,
+    pub lower80: Vec<f64>,
+    pub upper80: Vec<f64>,
+    pub lower95: Vec<f64>,
+    pub upper95: Vec<f64>,
+    pub hiLimitCrossing: Option<u32>,
+    pub loLimitCrossing: Option<u32>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct DecompositionStruct {
+    pub period: u32,
+    pub x: Vec<f64>,
+    pub trend: Vec<f64>,
+    pub seasonal: Vec<f64>,
+    pub residual: Vec<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct WarningStruct {
+    pub group: Option<u32>,
+    pub field: String,
+    pub message: String,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct TooltipRowStruct {
+    pub name: String,
+    pub value: String,
+    pub isFromData: bool,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct GroupStatsStruct {
+    pub keyValues: Option<KeyValuesStruct>,
+    pub stats: StatsStruct,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct YieldStruct {
+    pub keyValues: Option<KeyValuesStruct>,
+    pub count: u32,
+    pub passed: u32,
+    pub belowLimit: u32,
+    pub aboveLimit: u32,
+    pub yieldPercent: Option<f64>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct YieldSummaryStruct {
+    pub groups: Vec<YieldStruct>,
+    pub total: YieldStruct,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub enum ShapeEnum {
+    Line {
+        from: (f64, f64),
+        to: (f64, f64),
+        color: String,
+        width: f64,
+    },
+    Polyline {
+        points: Vec<(f64, f64)>,
+        color: String,
+        width: f64,
+    },
+    Circle {
+        center: (f64, f64),
+        radius: f64,
+        color: String,
+        filled: bool,
+    },
+    Hatch {
+        origin: (f64, f64),
+        size: (f64, f64),
+        color: String,
+    },
+    Text {
+        position: (f64, f64),
+        text: String,
+        anchor: String,
+        color: String,
+        size: f64,
+    },
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct SceneStruct {
+    pub width: f64,
+    pub height: f64,
+    pub plotArea: ((f64, f64), (f64, f64)),
+    pub plot: Vec<ShapeEnum>,
+    pub axes: Vec<ShapeEnum>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ReturnValuesStruct {
+    pub filterGroups: Vec<GroupItemStruct>,
+    // filterGroups with outliers as NaN when excludeOutliers is set, source of stats
+    pub statsGroups: Vec<GroupItemStruct>,
+    pub preprocessGroups: Vec<PreprocessItemStruct>,
+    pub displayGroups: Vec<Vec<PreprocessItemStruct>>,
+    pub groupsValues: Vec<Vec<f64>>,
+    pub domain: ([f64; 2], [f64; 2]),
+    pub shouldShowLimit: (f64, f64),
+    pub boxPlots: Vec<Option<BoxPlotStruct>>,
+    pub outliers: Vec<Vec<OutlierPointStruct>>,
+    pub siteComparison: Option<SiteComparisonStruct>,
+    pub changePoints: Vec<ChangePointsStruct>,
+    pub ewma: Vec<EwmaStruct>,
+    pub cusum: Vec<CusumStruct>,
+    pub smoothing: Vec<Vec<SeriesStruct>>,
+    pub rollingStats: Vec<RollingStatsStruct>,
+    pub autocorrelation: Vec<AutocorrelationStruct>,
+    pub forecasts: Vec<Option<ForecastStruct>>,
+    pub decomposition: Vec<Option<DecompositionStruct>>,
+    pub warnings: Vec<WarningStruct>,
+    pub glColors: Vec<[f64; 4]>,
+    pub info: InfoStruct,
+}
@@ -0,0 +1,156 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{statistics::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get values of group without NaN
+ * @method finiteValues
+ * @param {object} group
+ * @returns {array}
+ */
+fn finiteValues(group: &GroupItemStruct) -> Vec<f64> {
+    group
+        .values
+        .clone()
+        .unwrap_or(vec![])
+        .into_iter()
+        .filter(|value| !value.is_nan())
+        .collect()
+}
+
+/**
+ * Compute stats of values, std is the population one like Cp and Cpk
+ * @method valuesStats
+ * @param {array} values - values without NaN
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {object} - only count when values are empty
+ */
+pub fn valuesStats(values: &[f64], loLimit: Option<f64>, hiLimit: Option<f64>) -> StatsStruct {
+    if values.is_empty() {
+        return StatsStruct {
+            count: Some(0),
+            cp: None,
+            cpk: None,
+            max: None,
+            mean: None,
+            min: None,
+            std: None,
+        };
+    }
+
+    let m = mean(values);
+    let std = (values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / values.len() as f64).sqrt();
+
+    let capability = |index: f64| Some(index).filter(|index| index.is_finite());
+    let (cp, cpk) = match (loLimit, hiLimit) {
+        (Some(lo), Some(hi)) => (
+            capability((hi - lo) / (6.0 * std)),
+            capability((hi - m).min(m - lo) / (3.0 * std)),
+        ),
+        _ => (None, None),
+    };
+
+    StatsStruct {
+        count: Some(values.len() as u32),
+        cp,
+        cpk,
+        max: Some(values.iter().copied().fold(f64::NEG_INFINITY, f64::max)),
+        mean: Some(m),
+        min: Some(values.iter().copied().fold(f64::INFINITY, f64::min)),
+        std: Some(std),
+    }
+}
+
+/**
+ * Compute stats of every group
+ * @method groupsStats
+ * @param {array} groups - statsGroups of process, or groups returned by filterGroup
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {array}
+ */
+pub fn groupsStats(
+    groups: Vec<GroupItemStruct>,
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+) -> Vec<GroupStatsStruct> {
+    groups
+        .iter()
+        .map(|group| GroupStatsStruct {
+            keyValues: group.keyValues,
+            stats: valuesStats(&finiteValues(group), loLimit, hiLimit),
+        })
+        .collect()
+}
+
+/**
+ * Count values inside and beyond the limits
+ * @method valuesYield
+ * @param {array} values - values without NaN
+ * @param {number} loLimit - missing limit passes every value
+ * @param {number} hiLimit - missing limit passes every value
+ * @param {object} keyValues - null for the total
+ * @returns {object} - yieldPercent is null when values are empty, nothing passes inverted limits
+ */
+pub fn valuesYield(
+    values: &[f64],
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+    keyValues: Option<KeyValuesStruct>,
+) -> YieldStruct {
+    let belowLimit = values
+        .iter()
+        .filter(|v| loLimit.is_some_and(|lo| **v < lo))
+        .count() as u32;
+    let aboveLimit = values
+        .iter()
+        .filter(|v| hiLimit.is_some_and(|hi| **v > hi))
+        .count() as u32;
+    let count = values.len() as u32;
+
+    // Counted on its own, with LO_LIMIT above HI_LIMIT a value can be both below and above
+    let passed = values
+        .iter()
+        .filter(|v| loLimit.is_none_or(|lo| **v >= lo) && hiLimit.is_none_or(|hi| **v <= hi))
+        .count() as u32;
+
+    YieldStruct {
+        keyValues,
+        count,
+        passed,
+        belowLimit,
+        aboveLimit,
+        yieldPercent: Some(100.0 * passed as f64 / count as f64).filter(|_| count > 0),
+    }
+}
+
+/**
+ * Count values inside and beyond the limits for every group and in total
+ * @method yieldSummary
+ * @param {array} groups - statsGroups of process, or groups returned by filterGroup
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {object}
+ */
+pub fn yieldSummary(
+    groups: Vec<GroupItemStruct>,
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+) -> YieldSummaryStruct {
+    let groupsValues: Vec<Vec<f64>> = groups.iter().map(finiteValues).collect();
+    let allValues: Vec<f64> = groupsValues.concat();
+
+    YieldSummaryStruct {
+        groups: groups
+            .iter()
+            .zip(groupsValues.iter())
+            .map(|(group, values)| valuesYield(values, loLimit, hiLimit, group.keyValues))
+            .collect(),
+        total: valuesYield(&allValues, loLimit, hiLimit, None),
+    }
+}
@@ -0,0 +1,281 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::structs::*;
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get number of days since 1970-01-01 of a civil date (proleptic Gregorian)
+ * @method daysFromCivil
+ * @param {number} year
+ * @param {number} month - 1 to 12
+ * @param {number} day - 1 to 31
+ * @returns {number}
+ */
+pub fn daysFromCivil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = if year >= 0 { year } else { year - 399 } / 400;
+    let yearOfEra = year - era * 400;
+    let dayOfYear = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let dayOfEra = yearOfEra * 365 + yearOfEra / 4 - yearOfEra / 100 + dayOfYear;
+
+    era * 146097 + dayOfEra - 719468
+}
+
+/**
+ * Get civil date of a number of days since 1970-01-01 (proleptic Gregorian)
+ * @method civilFromDays
+ * @param {number} days
+ * @returns {array} - [year, month, day]
+ */
+pub fn civilFromDays(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = if days >= 0 { days } else { days - 146096 } / 146097;
+    let dayOfEra = days - era * 146097;
+    let yearOfEra = (dayOfEra - dayOfEra / 1460 + dayOfEra / 36524 - dayOfEra / 146096) / 365;
+    let dayOfYear = dayOfEra - (365 * yearOfEra + yearOfEra / 4 - yearOfEra / 100);
+    let monthIndex = (5 * dayOfYear + 2) / 153;
+    let day = dayOfYear - (153 * monthIndex + 2) / 5 + 1;
+    let month = if monthIndex < 10 {
+        monthIndex + 3
+    } else {
+        monthIndex - 9
+    };
+
+    (
+        yearOfEra + era * 400 + if month <= 2 { 1 } else { 0 },
+        month,
+        day,
+    )
+}
+
+/**
+ * Parse a fixed number of digits
+ * @method parseDigits
+ * @param {string} text
+ * @param {number} start
+ * @param {number} length
+ * @returns {number} - null when text is too short or not digits
+ */
+fn parseDigits(text: &str, start: usize, length: usize) -> Option<i64> {
+    let digits = text.get(start..start + length)?;
+
+    if !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+
+    digits.parse().ok()
+}
+
+/**
+ * Parse ISO 8601 date or date-time to epoch milliseconds
+ * Accepts "YYYY-MM-DD", "YYYY-MM-DDTHH:MM", seconds, fractions and "Z" or "+HH:MM" offsets,
+ * a missing offset is read as UTC
+ * @method parseIsoTimestamp
+ * @param {string} text
+ * @returns {number} - null when text is not a valid timestamp
+ */
+pub fn parseIsoTimestamp(text: &str) -> Option<f64> {
+    let text = text.trim();
+
+    let year = parseDigits(text, 0, 4)?;
+    let month = parseDigits(text, 5, 2)?;
+    let day = parseDigits(text, 8, 2)?;
+
+    if text.get(4..5)? != "-" || text.get(7..8)? != "-" || !(1..=12).contains(&month) {
+        return None;
+    }
+    if !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let mut milliseconds = daysFromCivil(year, month, day) as f64 * 86_400_000.0;
+    let mut rest = &text[10..];
+
+    if rest.is_empty() {
+        return Some(milliseconds);
+    }
+
+    // Time part, separated by "T" or a space
+    if !rest.starts_with('T') && !rest.starts_with(' ') {
+        return None;
+    }
+
+    let hours = parseDigits(rest, 1, 2)?;
+    let minutes = parseDigits(rest, 4, 2)?;
+
+    if rest.get(3..4)? != ":" || hours > 23 || minutes > 59 {
+        return None;
+    }
+
+    milliseconds += (hours * 3_600_000 + minutes * 60_000) as f64;
+    rest = &rest[6..];
+
+    if rest.starts_with(':') {
+        let seconds = parseDigits(rest, 1, 2)?;
+
+        if seconds > 60 {
+            return None;
+        }
+
+        milliseconds += (seconds * 1000) as f64;
+        rest = &rest[3..];
+
+        if rest.starts_with('.') || rest.starts_with(',') {
+            let fraction: String = rest[1..]
+                .chars()
+                .take_while(|c| c.is_ascii_digit())
+                .collect();
+
+            if fraction.is_empty() {
+                return None;
+            }
+
+            milliseconds += format!("0.{}", fraction).parse::<f64>().ok()? * 1000.0;
+            rest = &rest[1 + fraction.len()..];
+        }
+    }
+
+    // Offset from UTC
+    match rest {
+        "" | "Z" | "z" => Some(milliseconds),
+        _ => {
+            let sign = match rest.get(0..1)? {
+                "+" => 1.0,
+                "-" => -1.0,
+                _ => return None,
+            };
+            let offsetHours = parseDigits(rest, 1, 2)?;
+            let offsetMinutes = match rest.len() {
+                3 => 0,
+                5 => parseDigits(rest, 3, 2)?,
+                6 if rest.get(3..4)? == ":" => parseDigits(rest, 4, 2)?,
+                _ => return None,
+            };
+
+            Some(milliseconds - sign * (offsetHours * 3_600_000 + offsetMinutes * 60_000) as f64)
+        }
+    }
+}
+
+/**
+ * Get epoch milliseconds of timestamp
+ * @method timestampToEpoch
+ * @param {number|string} timestamp - epoch milliseconds or ISO 8601 string
+ * @returns {number} - null when timestamp is not valid
+ */
+pub fn timestampToEpoch(timestamp: &TimestampEnum) -> Option<f64> {
+    match timestamp {
+        TimestampEnum::Epoch(value) if value.is_finite() => Some(*value),
+        TimestampEnum::Epoch(_) => None,
+        TimestampEnum::Iso(text) => parseIsoTimestamp(text),
+    }
+}
+
+/**
+ * Get x values of group, epoch milliseconds when there is a timestamp per value, otherwise the index of the value
+ * @method groupXValues
+ * @param {object} group
+ * @returns {array} - NaN for an invalid timestamp, filterGroup leaves its value out
+ */
+pub fn groupXValues(group: &GroupItemStruct) -> Vec<f64> {
+    let length = group
+        .values
+        .as_ref()
+        .map(|values| values.len())
+        .unwrap_or(0);
+
+    // One bad timestamp must not move the whole group to the index axis
+    match group
+        .timestamps
+        .as_ref()
+        .filter(|timestamps| timestamps.len() == length)
+    {
+        Some(timestamps) => timestamps
+            .iter()
+            .map(|timestamp| timestampToEpoch(timestamp).unwrap_or(f64::NAN))
+            .collect(),
+        None => (0..length).map(|i| i as f64).collect(),
+    }
+}
+
+/**
+ * Keep timestamps of groups only in time mode, so every x of index mode is the value index
+ * @method applyXMode
+ * @param {array} groups
+ * @param {string} xMode - "time" or "index"
+ * @returns {array}
+ */
+pub fn applyXMode(groups: Vec<GroupItemStruct>, xMode: &str) -> Vec<GroupItemStruct> {
+    groups
+        .into_iter()
+        .map(|group| GroupItemStruct {
+            timestamps: group.timestamps.filter(|_| xMode == "time"),
+            ..group
+        })
+        .collect()
+}
+
+/**
+ * Report timestamps that can't be placed on the time axis
+ * @method timestampWarnings
+ * @param {array} groups - groups of data
+ * @returns {array} - warnings, the group of a warning is its index in data
+ */
+pub fn timestampWarnings(groups: &[GroupItemStruct]) -> Vec<WarningStruct> {
+    let mut warnings: Vec<WarningStruct> = Vec::new();
+
+    for (i, group) in groups.iter().enumerate() {
+        let values = group.values.clone().unwrap_or(vec![]);
+
+        let timestamps = match &group.timestamps {
+            Some(timestamps) if timestamps.len() == values.len() => timestamps,
+            _ => continue,
+        };
+
+        for (index, (value, timestamp)) in values.iter().zip(timestamps.iter()).enumerate() {
+            if value.is_nan() || timestampToEpoch(timestamp).is_some() {
+                continue;
+            }
+
+            let text = match timestamp {
+                TimestampEnum::Epoch(epoch) => epoch.to_string(),
+                TimestampEnum::Iso(text) => format!("\"{}\"", text),
+            };
+
+            warnings.push(WarningStruct {
+                group: Some(i as u32),
+                field: "timestamps".to_string(),
+                message: format!(
+                    "timestamp {} of value {} is not valid, the value is left out",
+                    text, index
+                ),
+            });
+        }
+    }
+
+    warnings
+}
+
+/**
+ * Extend x values by a number of steps, using the median spacing of x
+ * @method extrapolateXValues
+ * @param {array} xValues
+ * @param {number} steps
+ * @returns {array} - x of the next steps
+ */
+pub fn extrapolateXValues(xValues: &[f64], steps: usize) -> Vec<f64> {
+    let mut spacings: Vec<f64> = xValues.windows(2).map(|w| w[1] - w[0]).collect();
+    spacings.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let step = if spacings.is_empty() {
+        1.0
+    } else {
+        spacings[spacings.len() / 2]
+    };
+    let last = xValues.last().copied().unwrap_or(-1.0);
+
+    (1..=steps).map(|h| last + h as f64 * step).collect()
+}
@@ -0,0 +1,237 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, control_charts::*, number_parsing::*, outliers::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get rules broken by every value of group: spec limits, outlier detection and control charts of config
+ * @method groupViolations
+ * @param {object} group - group returned by filterGroup
+ * @param {object} config
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {array} - names of the broken rules of every value
+ */
+pub fn groupViolations(
+    group: &GroupItemStruct,
+    config: &ConfigStruct,
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+) -> Vec<Vec<String>> {
+    let values = group.values.clone().unwrap_or(vec![]);
+    let outlierFlags = groupsOutliers(vec![group.clone()], config);
+    let (ewma, cusum) = groupsControlCharts(vec![group.clone()], config);
+
+    values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let mut violations: Vec<String> = Vec::new();
+
+            if hiLimit.is_some_and(|hi| *value > hi) {
+                violations.push(RULE_ABOVE_HI_LIMIT.to_string());
+            }
+            if loLimit.is_some_and(|lo| *value < lo) {
+                violations.push(RULE_BELOW_LO_LIMIT.to_string());
+            }
+            if outlierFlags[0].get(index) == Some(&true) {
+                violations.push(RULE_OUTLIER.to_string());
+            }
+            if let Some(ewma) = ewma.first() {
+                if ewma.statistic[index] < ewma.lowerLimit[index]
+                    || ewma.statistic[index] > ewma.upperLimit[index]
+                {
+                    violations.push(RULE_EWMA.to_string());
+                }
+            }
+            if let Some(cusum) = cusum.first() {
+                if cusum.upperStatistic[index] > cusum.limit[index]
+                    || cusum.lowerStatistic[index] > cusum.limit[index]
+                {
+                    violations.push(RULE_CUSUM.to_string());
+                }
+            }
+
+            violations
+        })
+        .collect()
+}
+
+/**
+ * Get rules broken by a value of group
+ * @method pointViolations
+ * @param {object} group - group returned by filterGroup
+ * @param {number} index - index of the value
+ * @param {object} config
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {array} - names of the broken rules, empty when index is out of the values
+ */
+pub fn pointViolations(
+    group: &GroupItemStruct,
+    index: usize,
+    config: &ConfigStruct,
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+) -> Vec<String> {
+    groupViolations(group, config, loLimit, hiLimit)
+        .into_iter()
+        .nth(index)
+        .unwrap_or_default()
+}
+
+/**
+ * Get ordered tooltip rows of a value of group, same rows as showTooltip of the front-end
+ * @method tooltipRows
+ * @param {object} group - group returned by filterGroup
+ * @param {number} index - index of the value
+ * @param {string} units
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @param {array} violations - returned by pointViolations
+ * @param {array} fields - "keyValues", "Value", "Cp", "Cpk", "Max", "Mean", "Min", "Std", "Margin" or "Violations"
+ * @returns {array} - empty when index is out of the values
+ */
+pub fn tooltipRows(
+    group: &GroupItemStruct,
+    index: usize,
+    units: &str,
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+    violations: &[String],
+    fields: &[String],
+) -> Vec<TooltipRowStruct> {
+    let value = match group.values.as_ref().and_then(|values| values.get(index)) {
+        Some(value) => *value,
+        None => return vec![],
+    };
+
+    let row = |name: &str, value: String| TooltipRowStruct {
+        name: name.to_string(),
+        value,
+        isFromData: false,
+    };
+
+    // Formatted stats strings win over the numbers, like the front-end
+    let stats = group.stats.clone();
+    let statRow = |name: &str, text: Option<String>, number: Option<f64>, unit: &str| {
+        let formatted = match unit {
+            "" => number.map(|number| format!("{:.1}", number)),
+            _ => number.map(|number| formatEngineering(number, unit)),
+        };
+
+        row(name, text.or(formatted).unwrap_or_default())
+    };
+
+    let mut rows: Vec<TooltipRowStruct> = Vec::new();
+
+    for field in fields.iter() {
+        match field.as_str() {
+            "keyValues" => {
+                if let Some(site) = group.keyValues.and_then(|keyValues| keyValues.SITE_NUM) {
+                    rows.push(TooltipRowStruct {
+                        name: "SITE_NUM".to_string(),
+                        value: site.to_string(),
+                        isFromData: true,
+                    });
+                }
+            }
+            "Value" => rows.push(row("Value", formatEngineering(value, units))),
+            "Cp" => rows.push(statRow(
+                "Cp",
+                stats.as_ref().and_then(|stats| stats.Cp.clone()),
+                stats.as_ref().and_then(|stats| stats.cp),
+                "",
+            )),
+            "Cpk" => rows.push(statRow(
+                "Cpk",
+                stats.as_ref().and_then(|stats| stats.Cpk.clone()),
+                stats.as_ref().and_then(|stats| stats.cpk),
+                "",
+            )),
+            "Max" => rows.push(statRow(
+                "Max",
+                stats.as_ref().and_then(|stats| stats.Max.clone()),
+                stats.as_ref().and_then(|stats| stats.max),
+                units,
+            )),
+            "Mean" => rows.push(statRow(
+                "Mean",
+                stats.as_ref().and_then(|stats| stats.Mean.clone()),
+                stats.as_ref().and_then(|stats| stats.mean),
+                units,
+            )),
+            "Min" => rows.push(statRow(
+                "Min",
+                stats.as_ref().and_then(|stats| stats.Min.clone()),
+                stats.as_ref().and_then(|stats| stats.min),
+                units,
+            )),
+            "Std" => rows.push(statRow(
+                "Std",
+                stats.as_ref().and_then(|stats| stats.Std.clone()),
+                stats.as_ref().and_then(|stats| stats.std),
+                units,
+            )),
+            // Distance to the nearest limit, negative outside the limits
+            "Margin" => {
+                let margin = [hiLimit.map(|hi| hi - value), loLimit.map(|lo| value - lo)]
+                    .into_iter()
+                    .flatten()
+                    .reduce(f64::min);
+
+                if let Some(margin) = margin {
+                    rows.push(row("Margin", formatEngineering(margin, units)));
+                }
+            }
+            "Violations" if !violations.is_empty() => {
+                rows.push(row("Violations", violations.join(", ")));
+            }
+            _ => {}
+        }
+    }
+
+    rows
+}
+
+/**
+ * Get tooltip rows of a hit
+ * @method groupTooltip
+ * @param {object} group - group returned by filterGroup
+ * @param {number} index - index of the value
+ * @param {object} info
+ * @param {object} config
+ * @returns {array}
+ */
+pub fn groupTooltip(
+    group: &GroupItemStruct,
+    index: usize,
+    info: &InfoStruct,
+    config: &ConfigStruct,
+) -> Vec<TooltipRowStruct> {
+    let fields = config.tooltipFields.clone().unwrap_or(
+        DEFAULT_TOOLTIP_FIELDS
+            .iter()
+            .map(|field| field.to_string())
+            .collect(),
+    );
+
+    let violations = if fields.iter().any(|field| field == "Violations") {
+        pointViolations(group, index, config, info.LO_LIMIT, info.HI_LIMIT)
+    } else {
+        vec![]
+    };
+
+    tooltipRows(
+        group,
+        index,
+        info.UNITS.as_deref().unwrap_or(""),
+        info.LO_LIMIT,
+        info.HI_LIMIT,
+        &violations,
+        &fields,
+    )
+}
@@ -0,0 +1,138 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{number_parsing::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get base unit of a unit name, "Ohm" and the ohm sign are read as "Ω"
+ * @method baseUnit
+ * @param {string} text
+ * @returns {string} - null when unit is not a known base unit
+ */
+fn baseUnit(text: &str) -> Option<&'static str> {
+    match text {
+        "V" => Some("V"),
+        "A" => Some("A"),
+        "Ω" | "\u{2126}" | "Ohm" | "ohm" => Some("Ω"),
+        "Hz" => Some("Hz"),
+        "s" => Some("s"),
+        "W" => Some("W"),
+        "dB" => Some("dB"),
+        _ => None,
+    }
+}
+
+/**
+ * Parse unit with optional SI prefix, like "mV" or "µA"
+ * @method parseUnit
+ * @param {string} text
+ * @returns {array} - [scale to the base unit, base unit], error when unit is unknown
+ */
+pub fn parseUnit(text: &str) -> Result<(f64, &'static str), String> {
+    let text = text.trim();
+
+    if let Some(base) = baseUnit(text) {
+        return Ok((1.0, base));
+    }
+
+    let mut chars = text.chars();
+    let prefix = chars.next().and_then(siPrefixScale);
+
+    match (prefix, baseUnit(chars.as_str())) {
+        // Decibels are a ratio, a prefix has no meaning
+        (Some(_), Some("dB")) => Err(format!("unit \"{}\" can't have a prefix", text)),
+        (Some(scale), Some(base)) => Ok((scale, base)),
+        _ => Err(format!("unknown unit \"{}\"", text)),
+    }
+}
+
+/**
+ * Get factor converting values from one unit to another
+ * @method conversionFactor
+ * @param {string} from
+ * @param {string} to
+ * @returns {number} - error when units are unknown or of different quantities
+ */
+pub fn conversionFactor(from: &str, to: &str) -> Result<f64, String> {
+    let (fromScale, fromBase) = parseUnit(from)?;
+    let (toScale, toBase) = parseUnit(to)?;
+
+    if fromBase != toBase {
+        return Err(format!("can't convert \"{}\" to \"{}\"", from, to));
+    }
+
+    Ok(fromScale / toScale)
+}
+
+/**
+ * Convert values, limits and stats of data to the display unit of config
+ * Cp and Cpk are ratios and formatted stats strings keep their own prefix, both stay untouched
+ * @method convertData
+ * @param {object} data
+ * @param {object} config
+ * @returns {object} - data in display unit, error when the conversion is not possible
+ */
+pub fn convertData(data: DataStruct, config: &ConfigStruct) -> Result<DataStruct, String> {
+    let displayUnit = match &config.displayUnit {
+        Some(displayUnit) => displayUnit.clone(),
+        None => return Ok(data),
+    };
+
+    let units = data
+        .info
+        .as_ref()
+        .and_then(|info| info.UNITS.clone())
+        .ok_or(format!(
+            "data has no UNITS to convert to \"{}\"",
+            displayUnit
+        ))?;
+
+    if units == displayUnit {
+        return Ok(data);
+    }
+
+    let factor = conversionFactor(&units, &displayUnit)?;
+    let scale = |value: Option<f64>| value.map(|value| value * factor);
+
+    let groups = data.groups.map(|groups| {
+        groups
+            .into_iter()
+            .map(|group| GroupItemStruct {
+                values: group
+                    .values
+                    .map(|values| values.iter().map(|value| value * factor).collect()),
+                stats: group.stats.map(|stats| StatsGroupStruct {
+                    max: scale(stats.max),
+                    mean: scale(stats.mean),
+                    min: scale(stats.min),
+                    std: scale(stats.std),
+                    ..stats
+                }),
+                ..group
+            })
+            .collect()
+    });
+
+    let stats = data.stats.map(|stats| StatsStruct {
+        max: scale(stats.max),
+        mean: scale(stats.mean),
+        min: scale(stats.min),
+        std: scale(stats.std),
+        ..stats
+    });
+
+    let info = data.info.map(|info| InfoStruct {
+        HI_LIMIT: scale(info.HI_LIMIT),
+        LO_LIMIT: scale(info.LO_LIMIT),
+        UNITS: Some(displayUnit),
+    });
+
+    Ok(DataStruct {
+        groups,
+        stats,
+        info,
+    })
+}
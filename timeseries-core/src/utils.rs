@@ -0,0 +1,114 @@
+#![allow(non_snake_case)]
+#![allow(unused_assignments)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::structs::*;
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Get domain of data
+ * @method getDomain
+ * @param {Array} groups - groups of data
+ * @returns {Array} - domain of data
+ */
+pub fn getDomain(groups: Vec<PreprocessItemStruct>) -> ([f64; 2], [f64; 2]) {
+    let mut xDomain: [f64; 2] = [0.0, 0.0];
+    let mut yDomain: [f64; 2] = [0.0, 0.0];
+
+    if groups.is_empty() {
+        xDomain = [-1.0, 0.0];
+        yDomain = [0.0, 0.0];
+    } else {
+        let mut sortedByY = groups.clone();
+        sortedByY.sort_by(|a, b| a.y.partial_cmp(&b.y).unwrap());
+
+        let minY = sortedByY[0].y;
+        let maxY = sortedByY[sortedByY.len() - 1].y;
+
+        yDomain = [minY - 0.0001, maxY + 0.0001];
+
+        let mut sortedByX = groups.clone();
+        sortedByX.sort_by(|a, b| a.x.partial_cmp(&b.x).unwrap());
+
+        let maxX = sortedByX[sortedByX.len() - 1].x;
+
+        xDomain = [-1.0, maxX + 3.0];
+    }
+
+    (xDomain, yDomain)
+}
+
+/**
+ * Get domain of data when x is a timestamp
+ * @method getTimeDomain
+ * @param {Array} groups - groups of data
+ * @returns {Array} - domain of data, x padded by 2% of the time span
+ */
+pub fn getTimeDomain(groups: Vec<PreprocessItemStruct>) -> ([f64; 2], [f64; 2]) {
+    let (_, yDomain) = getDomain(groups.clone());
+
+    if groups.is_empty() {
+        return ([0.0, 0.0], yDomain);
+    }
+
+    let minX = groups
+        .iter()
+        .map(|item| item.x)
+        .fold(f64::INFINITY, f64::min);
+    let maxX = groups
+        .iter()
+        .map(|item| item.x)
+        .fold(f64::NEG_INFINITY, f64::max);
+
+    // Single timestamp, pad by one second
+    let padding = if maxX > minX {
+        (maxX - minX) * 0.02
+    } else {
+        1000.0
+    };
+
+    ([minX - padding, maxX + padding], yDomain)
+}
+
+/**
+ * Check if should show limit
+ * @method shouldShowLimit
+ * @param {Number} hiLimit - high limit
+ * @param {Number} loLimit - low limit
+ * @returns {Array} - should show limit
+ */
+pub fn shouldShowLimit(hiLimit: f64, loLimit: f64) -> (f64, f64) {
+    let mut lowLimit: f64 = 0.0;
+    let mut hightLimit: f64 = 0.0;
+
+    if !loLimit.is_nan() && !hiLimit.is_nan() && loLimit < hiLimit {
+        lowLimit = loLimit;
+        hightLimit = hiLimit;
+    }
+    if !loLimit.is_nan() && hiLimit.is_nan() {
+        lowLimit = loLimit;
+    }
+    if loLimit.is_nan() && !hiLimit.is_nan() {
+        hightLimit = hiLimit;
+    }
+
+    (lowLimit, hightLimit)
+}
+
+/**
+ * Check if point is approx to coord
+ * @method checkApprox
+ * @param {number} point
+ * @param {number} coord
+ * @param {number} epsilon
+ * @returns {boolean}
+ */
+pub fn checkApprox(point: Option<f64>, coord: Option<f64>, epsilon: Option<f64>) -> bool {
+    let verifiedPoint = point.unwrap_or(0.0).abs();
+    let verifiedCoord = coord.unwrap_or(0.0).abs();
+    let verifiedEpsilon = epsilon.unwrap_or(0.0001).abs();
+
+    (verifiedPoint - verifiedCoord).abs() <= verifiedEpsilon
+}
@@ -0,0 +1,171 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use crate::{consts::*, statistics::*, structs::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Check whether a provided stat is within relative tolerance of one of the expected values
+ * @method agrees
+ * @param {number} provided
+ * @param {array} expected
+ * @param {number} tolerance
+ * @returns {boolean}
+ */
+fn agrees(provided: f64, expected: &[f64], tolerance: f64) -> bool {
+    expected.iter().any(|expected| {
+        (provided - expected).abs() <= tolerance * provided.abs().max(expected.abs()) + 1e-12
+    })
+}
+
+/**
+ * Compare provided stats with the stats implied by values
+ * Std may be the population or the sample standard deviation, Cp and Cpk follow the population one,
+ * counts are compared exactly
+ * @method validateStats
+ * @param {array} provided - [field, provided value] pairs
+ * @param {array} values - values without NaN
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @param {number} tolerance - allowed relative difference
+ * @param {number} group - index of the group in data, null for the dataset stats
+ * @returns {array} - warnings
+ */
+pub fn validateStats(
+    provided: &[(&str, Option<f64>)],
+    values: &[f64],
+    loLimit: Option<f64>,
+    hiLimit: Option<f64>,
+    tolerance: f64,
+    group: Option<u32>,
+) -> Vec<WarningStruct> {
+    let n = values.len() as f64;
+    let m = mean(values);
+    let populationStd = (values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / n).sqrt();
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    let capability = |index: f64| -> Vec<f64> {
+        if index.is_finite() {
+            vec![index]
+        } else {
+            vec![]
+        }
+    };
+
+    provided
+        .iter()
+        .filter_map(|(field, value)| {
+            let value = (*value)?;
+
+            // A count is an integer, any difference is a data error
+            if matches!(*field, "count" | "Count") {
+                return (value != n).then(|| WarningStruct {
+                    group,
+                    field: field.to_string(),
+                    message: format!("{} is {} but values imply {}", field, value, n),
+                });
+            }
+
+            let expected: Vec<f64> = match *field {
+                _ if values.is_empty() => vec![],
+                "mean" => vec![m],
+                "min" => vec![min],
+                "max" => vec![max],
+                "std" if values.len() > 1 => vec![populationStd, standardDeviation(values)],
+                "std" => vec![populationStd],
+                "cp" => match (loLimit, hiLimit) {
+                    (Some(lo), Some(hi)) => capability((hi - lo) / (6.0 * populationStd)),
+                    _ => vec![],
+                },
+                "cpk" => match (loLimit, hiLimit) {
+                    (Some(lo), Some(hi)) => {
+                        capability((hi - m).min(m - lo) / (3.0 * populationStd))
+                    }
+                    _ => vec![],
+                },
+                _ => vec![],
+            };
+
+            if expected.is_empty() || agrees(value, &expected, tolerance) {
+                return None;
+            }
+
+            Some(WarningStruct {
+                group,
+                field: field.to_string(),
+                message: format!("{} is {} but values imply {}", field, value, expected[0]),
+            })
+        })
+        .collect()
+}
+
+/**
+ * Compare stats of every group and of the dataset with their values
+ * @method validateData
+ * @param {object} data
+ * @param {object} config
+ * @returns {array} - warnings, the group of a warning is its index in data
+ */
+pub fn validateData(data: &DataStruct, config: &ConfigStruct) -> Vec<WarningStruct> {
+    let tolerance = config.statsTolerance.unwrap_or(DEFAULT_STATS_TOLERANCE);
+    let loLimit = data.info.as_ref().and_then(|info| info.LO_LIMIT);
+    let hiLimit = data.info.as_ref().and_then(|info| info.HI_LIMIT);
+
+    let groups = data.groups.clone().unwrap_or(vec![]);
+    let mut warnings: Vec<WarningStruct> = Vec::new();
+    let mut allValues: Vec<f64> = Vec::new();
+
+    for (i, group) in groups.iter().enumerate() {
+        let values: Vec<f64> = group
+            .values
+            .clone()
+            .unwrap_or(vec![])
+            .into_iter()
+            .filter(|value| !value.is_nan())
+            .collect();
+
+        if let Some(stats) = &group.stats {
+            let provided = [
+                ("Count", stats.Count.map(|count| count as f64)),
+                ("cp", stats.cp),
+                ("cpk", stats.cpk),
+                ("max", stats.max),
+                ("mean", stats.mean),
+                ("min", stats.min),
+                ("std", stats.std),
+            ];
+
+            warnings.extend(validateStats(
+                &provided,
+                &values,
+                loLimit,
+                hiLimit,
+                tolerance,
+                Some(i as u32),
+            ));
+        }
+
+        allValues.extend(values);
+    }
+
+    if let Some(stats) = &data.stats {
+        let provided = [
+            ("count", stats.count.map(|count| count as f64)),
+            ("cp", stats.cp),
+            ("cpk", stats.cpk),
+            ("max", stats.max),
+            ("mean", stats.mean),
+            ("min", stats.min),
+            ("std", stats.std),
+        ];
+
+        warnings.extend(validateStats(
+            &provided, &allValues, loLimit, hiLimit, tolerance, None,
+        ));
+    }
+
+    warnings
+}
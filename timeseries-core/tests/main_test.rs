@@ -0,0 +1,1637 @@
+#![allow(non_snake_case)]
+
+use timeseries_core::{
+    autocorrelation::*, box_plot::*, change_points::*, colors::*, consts::*, control_charts::*,
+    csv_import::*, data_preprocess::*, decomposition::*, downsampling::*, forecasting::*,
+    number_parsing::*, outliers::*, palettes::*, pipeline::*, raster::*, render::*, resampling::*,
+    rolling_stats::*, site_comparison::*, smoothing::*, statistics::*, structs::*, summary::*,
+    timestamps::*, tooltip::*, units::*, utils::*, validation::*,
+};
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_approx() {
+        let point = 0.0001;
+        let coord = 0.0002;
+        let epsilon = 0.0001;
+
+        let result = checkApprox(Some(point), Some(coord), Some(epsilon));
+
+        assert!(result);
+    }
+
+    #[test]
+    fn test_groups_values_list() {
+        let group1 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, 2.0, 3.0]),
+            timestamps: None,
+        };
+
+        // Vec::new(1.0, 2.0, 3.0);
+
+        let group2 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![4.0, 5.0, 6.0]),
+            timestamps: None,
+        };
+
+        let group3 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![6.0, 7.0, 8.0]),
+            timestamps: None,
+        };
+
+        let group4 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.5, 2.3, 7.1]),
+            timestamps: None,
+        };
+
+        let groups = vec![group1, group2, group3, group4];
+
+        let result = groupsValuesList(groups);
+
+        let expected_output: Vec<Vec<f64>> = [
+            [1.0, 4.0],
+            [2.0, 5.0],
+            [3.0, 6.0],
+            [4.0, 6.0],
+            [5.0, 7.0],
+            [6.0, 8.0],
+            [6.0, 1.5],
+            [7.0, 2.3],
+            [8.0, 7.1],
+        ]
+        .iter()
+        .map(|list| list.to_vec())
+        .collect::<Vec<Vec<f64>>>();
+
+        assert_eq!(result, expected_output);
+    }
+
+    #[test]
+    fn test_filterGroups() {
+        let group1 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, 2.0, 3.0]),
+            timestamps: None,
+        };
+
+        let group2 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![4.0, 5.0, 6.0]),
+            timestamps: None,
+        };
+
+        let group3 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![6.0, 7.0, 8.0]),
+            timestamps: None,
+        };
+
+        let group4 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.5, 2.3, 7.1]),
+            timestamps: None,
+        };
+
+        let groups = vec![group1, group2, group3, group4];
+
+        let hiddenGroups = vec![1, 3];
+
+        let filterGroups = filterGroup(groups, hiddenGroups);
+
+        let expectedFilterGroups = vec![
+            GroupItemStruct {
+                keyValues: None,
+                color: None,
+                stats: None,
+                values: Some(vec![1.0, 2.0, 3.0]),
+                timestamps: None,
+            },
+            GroupItemStruct {
+                keyValues: None,
+                color: None,
+                stats: None,
+                values: Some(vec![6.0, 7.0, 8.0]),
+                timestamps: None,
+            },
+        ];
+
+        assert_eq!(filterGroups, expectedFilterGroups);
+    }
+
+    #[test]
+    fn test_preprocessGroups() {
+        let group1 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, 2.0, 3.0]),
+            timestamps: None,
+        };
+
+        let group2 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![4.0, 5.0, 6.0]),
+            timestamps: None,
+        };
+
+        let group3 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![6.0, 7.0, 8.0]),
+            timestamps: None,
+        };
+
+        let group4 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.5, 2.3, 7.1]),
+            timestamps: None,
+        };
+
+        let groups = vec![group1, group2, group3, group4];
+
+        let hiddenGroups = vec![1, 3];
+
+        let filterGroups = filterGroup(groups, hiddenGroups);
+
+        let preprocessGroups = preprocess(filterGroups.clone());
+
+        let expectedPreprocessGroups = vec![
+            PreprocessItemStruct {
+                color: "#27ae60".to_string(),
+                keyValues: KeyValuesStruct { SITE_NUM: None },
+                stats: StatsGroupStruct {
+                    Count: None,
+                    Cp: None,
+                    Cpk: None,
+                    Max: None,
+                    Mean: None,
+                    Min: None,
+                    Std: None,
+                    cp: None,
+                    cpk: None,
+                    max: None,
+                    mean: None,
+                    min: None,
+                    std: None,
+                },
+                value: 1.0,
+                x: 0.0,
+                y: 1.0,
+                outlier: false,
+            },
+            PreprocessItemStruct {
+                color: "#27ae60".to_string(),
+                keyValues: KeyValuesStruct { SITE_NUM: None },
+                stats: StatsGroupStruct {
+                    Count: None,
+                    Cp: None,
+                    Cpk: None,
+                    Max: None,
+                    Mean: None,
+                    Min: None,
+                    Std: None,
+                    cp: None,
+                    cpk: None,
+                    max: None,
+                    mean: None,
+                    min: None,
+                    std: None,
+                },
+                value: 2.0,
+                x: 1.0,
+                y: 2.0,
+                outlier: false,
+            },
+            PreprocessItemStruct {
+                color: "#27ae60".to_string(),
+                keyValues: KeyValuesStruct { SITE_NUM: None },
+                stats: StatsGroupStruct {
+                    Count: None,
+                    Cp: None,
+                    Cpk: None,
+                    Max: None,
+                    Mean: None,
+                    Min: None,
+                    Std: None,
+                    cp: None,
+                    cpk: None,
+                    max: None,
+                    mean: None,
+                    min: None,
+                    std: None,
+                },
+                value: 3.0,
+                x: 2.0,
+                y: 3.0,
+                outlier: false,
+            },
+            PreprocessItemStruct {
+                color: "#27ae60".to_string(),
+                keyValues: KeyValuesStruct { SITE_NUM: None },
+                stats: StatsGroupStruct {
+                    Count: None,
+                    Cp: None,
+                    Cpk: None,
+                    Max: None,
+                    Mean: None,
+                    Min: None,
+                    Std: None,
+                    cp: None,
+                    cpk: None,
+                    max: None,
+                    mean: None,
+                    min: None,
+                    std: None,
+                },
+                value: 6.0,
+                x: 0.0,
+                y: 6.0,
+                outlier: false,
+            },
+            PreprocessItemStruct {
+                color: "#27ae60".to_string(),
+                keyValues: KeyValuesStruct { SITE_NUM: None },
+                stats: StatsGroupStruct {
+                    Count: None,
+                    Cp: None,
+                    Cpk: None,
+                    Max: None,
+                    Mean: None,
+                    Min: None,
+                    Std: None,
+                    cp: None,
+                    cpk: None,
+                    max: None,
+                    mean: None,
+                    min: None,
+                    std: None,
+                },
+                value: 7.0,
+                x: 1.0,
+                y: 7.0,
+                outlier: false,
+            },
+            PreprocessItemStruct {
+                color: "#27ae60".to_string(),
+                keyValues: KeyValuesStruct { SITE_NUM: None },
+                stats: StatsGroupStruct {
+                    Count: None,
+                    Cp: None,
+                    Cpk: None,
+                    Max: None,
+                    Mean: None,
+                    Min: None,
+                    Std: None,
+                    cp: None,
+                    cpk: None,
+                    max: None,
+                    mean: None,
+                    min: None,
+                    std: None,
+                },
+                value: 8.0,
+                x: 2.0,
+                y: 8.0,
+                outlier: false,
+            },
+        ];
+
+        assert_eq!(preprocessGroups, expectedPreprocessGroups);
+    }
+
+    #[test]
+    fn test_groupsValues() {
+        let group1 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, 2.0, 3.0]),
+            timestamps: None,
+        };
+
+        let group2 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![4.0, 5.0, 6.0]),
+            timestamps: None,
+        };
+
+        let group3 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![6.0, 7.0, 8.0]),
+            timestamps: None,
+        };
+
+        let group4 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.5, 2.3, 7.1]),
+            timestamps: None,
+        };
+
+        let groups = vec![group1, group2, group3, group4];
+
+        let hiddenGroups = vec![1, 3];
+
+        let filterGroups = filterGroup(groups, hiddenGroups);
+
+        let groupsValues = groupsValuesList(filterGroups.clone());
+
+        let expectedGroupsValues = vec![vec![1.0, 6.0], vec![2.0, 7.0], vec![3.0, 8.0]];
+
+        assert_eq!(groupsValues, expectedGroupsValues);
+    }
+
+    #[test]
+    fn test_domain() {
+        let group1 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, 2.0, 3.0]),
+            timestamps: None,
+        };
+
+        let group2 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![4.0, 5.0, 6.0]),
+            timestamps: None,
+        };
+
+        let group3 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![6.0, 7.0, 8.0]),
+            timestamps: None,
+        };
+
+        let group4 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.5, 2.3, 7.1]),
+            timestamps: None,
+        };
+
+        let groups = vec![group1, group2, group3, group4];
+
+        let hiddenGroups = vec![1, 3];
+
+        let filterGroups = filterGroup(groups, hiddenGroups);
+
+        let preprocessGroups = preprocess(filterGroups.clone());
+
+        let domain = getDomain(preprocessGroups.clone());
+
+        let expectedDomain = ([-1.0, 5.0], [0.9999, 8.0001]);
+
+        assert_eq!(domain, expectedDomain);
+    }
+
+    #[test]
+    fn test_shouldShowLimit() {
+        let HI_LIMIT = -0.44201892614364624;
+        let LO_LIMIT = -0.4467531909942627;
+
+        let showLimit = shouldShowLimit(HI_LIMIT, LO_LIMIT);
+
+        let expectedShowLimit = (-0.4467531909942627, -0.44201892614364624);
+
+        assert_eq!(showLimit, expectedShowLimit);
+    }
+
+    #[test]
+    fn test_boxPlots() {
+        let group1 = GroupItemStruct {
+            keyValues: Some(KeyValuesStruct { SITE_NUM: Some(1) }),
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, 2.0, f64::NAN, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 40.0]),
+            timestamps: None,
+        };
+
+        let group2 = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![]),
+            timestamps: None,
+        };
+
+        let filterGroups = filterGroup(vec![group1, group2], vec![]);
+
+        let boxPlots = boxPlots(filterGroups, "tukey".to_string(), [5.0, 95.0]);
+
+        let expectedBoxPlot = BoxPlotStruct {
+            keyValues: KeyValuesStruct { SITE_NUM: Some(1) },
+            color: "#27ae60".to_string(),
+            count: 9,
+            min: 1.0,
+            q1: 3.0,
+            median: 5.0,
+            q3: 7.0,
+            max: 40.0,
+            iqr: 4.0,
+            lowerWhisker: 1.0,
+            upperWhisker: 8.0,
+            outliers: vec![OutlierPointStruct {
+                x: 8.0,
+                value: 40.0,
+            }],
+        };
+
+        assert_eq!(boxPlots, vec![Some(expectedBoxPlot), None]);
+    }
+
+    #[test]
+    fn test_studentTQuantile() {
+        let t = studentTQuantile(0.975, 10.0);
+
+        assert!((t - 2.228138851986).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_outliers() {
+        let values = vec![
+            10.1, 9.8, 10.0, 10.2, 9.9, 10.1, 10.0, 9.7, 10.3, 10.0, 15.0, 9.9,
+        ];
+
+        let mut expectedFlags = vec![false; values.len()];
+        expectedFlags[10] = true;
+
+        assert_eq!(iqrOutliers(&values, 1.5), expectedFlags);
+        assert_eq!(madOutliers(&values, 3.5), expectedFlags);
+        assert_eq!(grubbsOutliers(&values, 0.05), expectedFlags);
+        assert_eq!(esdOutliers(&values, 0.05, 3), expectedFlags);
+
+        let groups = vec![GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(values),
+            timestamps: None,
+        }];
+
+        let outliers = outlierPoints(groups, vec![expectedFlags]);
+
+        assert_eq!(
+            outliers,
+            vec![vec![OutlierPointStruct {
+                x: 10.0,
+                value: 15.0
+            }]]
+        );
+    }
+
+    #[test]
+    fn test_siteComparison() {
+        let samples = vec![
+            vec![1.0, 2.0, 3.0],
+            vec![4.0, 5.0, 6.0],
+            vec![7.0, 8.0, 9.0],
+        ];
+
+        let anova = oneWayAnova(&samples);
+
+        assert!((anova.statistic - 27.0).abs() < 1e-9);
+        assert!((anova.pValue - 0.001).abs() < 1e-9);
+
+        let kruskal = kruskalWallis(&samples);
+
+        assert!((kruskal.statistic - 7.2).abs() < 1e-9);
+        assert!((kruskal.pValue - (-3.6_f64).exp()).abs() < 1e-9);
+
+        let levene = leveneTest(&samples);
+
+        assert_eq!(levene.pValue, 1.0);
+
+        assert_eq!(
+            adjustPValues(&[0.01, 0.04, 0.03], "holm"),
+            vec![0.03, 0.06, 0.06]
+        );
+
+        let groups = samples
+            .into_iter()
+            .enumerate()
+            .map(|(i, values)| GroupItemStruct {
+                keyValues: Some(KeyValuesStruct {
+                    SITE_NUM: Some(i as u32),
+                }),
+                color: None,
+                stats: None,
+                values: Some(values),
+                timestamps: None,
+            })
+            .collect();
+
+        let comparison = siteComparison(groups, 0.05, "holm".to_string()).unwrap();
+
+        assert_eq!(comparison.meanDeltas[0][2], -6.0);
+        assert_eq!(comparison.meanDeltas[2][0], 6.0);
+        assert_eq!(comparison.pValues[1][1], 1.0);
+
+        // NaN p-values sort last without a panic and are never flagged
+        assert_eq!(ranks(&[2.0, f64::NAN, 1.0])[..2], [2.0, 3.0]);
+
+        let adjusted = adjustPValues(&[0.01, f64::NAN, 0.02], "holm");
+
+        assert_eq!((adjusted[0], adjusted[2]), (0.02, 0.02));
+        assert!(adjusted[1].is_nan());
+
+        // Two constant sites, the variance of the third overflows
+        let groups = [
+            vec![1.0, 1.0, 1.0],
+            vec![1.0, 1.0],
+            vec![f64::MAX, -f64::MAX],
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, values)| GroupItemStruct {
+            keyValues: Some(KeyValuesStruct {
+                SITE_NUM: Some(i as u32),
+            }),
+            color: None,
+            stats: None,
+            values: Some(values),
+            timestamps: None,
+        })
+        .collect();
+
+        let comparison = siteComparison(groups, 0.05, "holm".to_string()).unwrap();
+
+        assert_eq!(comparison.pValues[0][1], 1.0);
+        assert!(comparison.pValues[0][2].is_nan());
+        assert_eq!(comparison.differentSites, vec![false, false, false]);
+    }
+
+    #[test]
+    fn test_changePoints() {
+        let values = vec![0.1, -0.1, 0.0, 0.2, -0.2, 0.1, 5.1, 4.9, 5.0, 5.2, 4.8, 5.0];
+        let penalty = 2.0 * (values.len() as f64).ln();
+
+        assert_eq!(peltChangePoints(&values, "mean", penalty, 2), vec![6]);
+        assert_eq!(
+            binarySegmentationChangePoints(&values, "mean", penalty, 5, 2),
+            vec![6]
+        );
+        assert_eq!(cusumChangePoints(&values, 5, 2), vec![6]);
+
+        let segments = segmentsStats(&values, &[6]);
+
+        assert_eq!(segments.len(), 2);
+        assert_eq!(
+            (segments[1].start, segments[1].end, segments[1].count),
+            (6, 11, 6)
+        );
+        assert!((segments[1].mean - 5.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_controlCharts() {
+        let values = vec![
+            10.0, 10.2, 9.8, 10.1, 9.9, 10.0, 10.2, 9.8, 10.1, 9.9, 10.3, 10.4, 10.3, 10.5, 10.4,
+            10.3, 10.5, 10.4,
+        ];
+
+        let ewma = ewmaChart(&values, 0.2, 3.0, 10);
+
+        assert_eq!(ewma.x.len(), values.len());
+        assert!((ewma.center - 10.0).abs() < 1e-9);
+        assert!((ewma.statistic[0] - 10.0).abs() < 1e-9);
+        assert!(ewma.upperLimit[0] < ewma.upperLimit[values.len() - 1]);
+        assert!(ewma.firstOutOfControl.unwrap() >= 10);
+
+        let cusum = cusumChart(&values, 0.5, 5.0, 10);
+
+        assert!(cusum.lowerStatistic.iter().sum::<f64>() < 1.0);
+        assert!(cusum.firstOutOfControl.unwrap() >= 10);
+
+        // Baselines without a moving range, or longer than the group, use the whole group
+        let whole = ewmaChart(&values, 0.2, 3.0, values.len());
+
+        for baseline in [0, 1, 100] {
+            let ewma = ewmaChart(&values, 0.2, 3.0, baseline);
+            let cusum = cusumChart(&values, 0.5, 5.0, baseline);
+
+            assert_eq!(
+                (ewma.center, ewma.upperLimit.clone()),
+                (whole.center, whole.upperLimit.clone())
+            );
+            assert!(cusum.limit[0].is_finite() && cusum.limit[0] > 0.0);
+        }
+
+        assert_eq!(baselineValues(&values, 1).len(), values.len());
+        assert_eq!(baselineValues(&values, 2), &values[..2]);
+    }
+
+    #[test]
+    fn test_smoothing() {
+        let values = vec![1.0, 3.0, 2.0, 4.0, 3.0, 5.0];
+
+        assert_eq!(
+            simpleMovingAverage(&values, 2),
+            vec![1.0, 2.0, 2.5, 3.0, 3.5, 4.0]
+        );
+        assert_eq!(
+            rollingMedian(&values, 3),
+            vec![2.0, 2.0, 3.0, 3.0, 4.0, 4.0]
+        );
+
+        let ema = exponentialMovingAverage(&values, 3);
+
+        assert_eq!(ema[0], 1.0);
+        assert!((ema[1] - 2.0).abs() < 1e-12);
+
+        // Local polynomial fits reproduce a straight line exactly
+        let line: Vec<f64> = (0..8).map(|i| 2.0 * i as f64 + 1.0).collect();
+
+        for smoothed in [savitzkyGolay(&line, 5, 2), loess(&line, 4)] {
+            for (a, b) in smoothed.iter().zip(line.iter()) {
+                assert!((a - b).abs() < 1e-9);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rollingStatistics() {
+        let values = vec![1.0, 3.0, 2.0, 6.0, 4.0];
+
+        let rolling = rollingStatistics(&values, 3, 2.0);
+
+        assert_eq!(rolling.mean, vec![1.0, 2.0, 2.0, 11.0 / 3.0, 4.0]);
+        assert_eq!(rolling.min, vec![1.0, 1.0, 1.0, 2.0, 2.0]);
+        assert_eq!(rolling.max, vec![1.0, 3.0, 3.0, 6.0, 6.0]);
+        assert_eq!(rolling.std[0], 0.0);
+        assert!((rolling.std[2] - 1.0).abs() < 1e-12);
+        assert!((rolling.std[4] - 2.0).abs() < 1e-12);
+        assert!((rolling.upperBand[4] - 8.0).abs() < 1e-12);
+
+        // A missing sample only leaves its windows, a window of NaN only has no stats
+        let values = vec![1.0, f64::NAN, 3.0, 5.0, f64::NAN, f64::NAN, 7.0];
+
+        let rolling = rollingStatistics(&values, 2, 2.0);
+
+        assert_eq!(rolling.mean[..5], [1.0, 1.0, 3.0, 4.0, 5.0]);
+        assert_eq!(rolling.min[..5], [1.0, 1.0, 3.0, 3.0, 5.0]);
+        assert!((rolling.std[3] - 2.0f64.sqrt()).abs() < 1e-12);
+        assert!(rolling.mean[5].is_nan() && rolling.max[5].is_nan());
+        assert_eq!((rolling.mean[6], rolling.std[6]), (7.0, 0.0));
+    }
+
+    #[test]
+    fn test_timestamps() {
+        assert_eq!(parseIsoTimestamp("1970-01-02"), Some(86_400_000.0));
+        assert_eq!(
+            parseIsoTimestamp("2023-03-01T10:20:30.5Z"),
+            Some(1_677_666_030_500.0)
+        );
+        assert_eq!(
+            parseIsoTimestamp("2023-03-01T12:20:30.5+02:00"),
+            Some(1_677_666_030_500.0)
+        );
+        assert_eq!(parseIsoTimestamp("2023-13-01"), None);
+
+        let group = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, f64::NAN, 3.0]),
+            timestamps: Some(vec![
+                TimestampEnum::Epoch(1000.0),
+                TimestampEnum::Epoch(2000.0),
+                TimestampEnum::Iso("1970-01-01T00:00:03Z".to_string()),
+            ]),
+        };
+
+        let filterGroups = filterGroup(vec![group], vec![]);
+        let preprocessGroups = preprocess(filterGroups);
+
+        let xValues: Vec<f64> = preprocessGroups.iter().map(|item| item.x).collect();
+
+        assert_eq!(xValues, vec![1000.0, 3000.0]);
+
+        let domain = getTimeDomain(preprocessGroups);
+
+        assert_eq!(domain.0, [960.0, 3040.0]);
+
+        // A bad timestamp leaves its value out, the group stays on the time axis
+        let data = DataStruct {
+            groups: Some(vec![GroupItemStruct {
+                keyValues: None,
+                color: None,
+                stats: None,
+                values: Some(vec![1.0, 2.0, 3.0]),
+                timestamps: Some(vec![
+                    TimestampEnum::Epoch(1000.0),
+                    TimestampEnum::Iso("yesterday".to_string()),
+                    TimestampEnum::Epoch(3000.0),
+                ]),
+            }]),
+            stats: None,
+            info: None,
+        };
+
+        let groups = data.groups.clone().unwrap();
+
+        assert!(groupXValues(&groups[0])[1].is_nan());
+        assert_eq!(groupXValues(&groups[0])[2], 3000.0);
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "xMode": "time" }"#).unwrap();
+
+        let result = process(data.clone(), &config).unwrap();
+        let xValues: Vec<f64> = result.preprocessGroups.iter().map(|item| item.x).collect();
+
+        assert_eq!(xValues, vec![1000.0, 3000.0]);
+        assert_eq!(result.warnings.len(), 1);
+        assert_eq!(
+            (result.warnings[0].group, result.warnings[0].field.as_str()),
+            (Some(0), "timestamps")
+        );
+
+        // Index mode ignores timestamps, every value stays
+        let config: ConfigStruct = serde_json::from_str(r#"{ "limitHighlight": false }"#).unwrap();
+
+        let result = process(data, &config).unwrap();
+
+        assert_eq!(result.preprocessGroups.len(), 3);
+        assert!(result.warnings.is_empty());
+    }
+
+    #[test]
+    fn test_downsampling() {
+        // Dense burst, then a long gap, then two points
+        let xValues = vec![0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 100.0, 101.0];
+        let yValues = vec![1.0, 9.0, 2.0, 8.0, 3.0, 7.0, 5.0, 6.0];
+
+        assert_eq!(downsampleIndexes(&xValues, &yValues, 8).len(), 8);
+
+        // Buckets split time, the burst keeps its extremes and the points after the gap stay
+        assert_eq!(downsampleIndexes(&xValues, &yValues, 4), vec![0, 1, 6, 7]);
+
+        let data = DataStruct {
+            groups: Some(vec![GroupItemStruct {
+                color: None,
+                keyValues: None,
+                stats: None,
+                values: Some(vec![1.0, 9.0, 2.0, 8.0, 3.0, 7.0, 5.0, 6.0]),
+                timestamps: None,
+            }]),
+            stats: None,
+            info: None,
+        };
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "maxPoints": 4 }"#).unwrap();
+
+        let result = process(data.clone(), &config).unwrap();
+        let xValues: Vec<f64> = result.displayGroups[0].iter().map(|item| item.x).collect();
+
+        assert_eq!(xValues, vec![0.0, 1.0, 4.0, 5.0]);
+        assert_eq!(result.preprocessGroups.len(), 8);
+
+        // Without maxPoints every point is drawn
+        let config: ConfigStruct = serde_json::from_str(r#"{ "limitHighlight": false }"#).unwrap();
+
+        assert_eq!(process(data, &config).unwrap().displayGroups[0].len(), 8);
+    }
+
+    #[test]
+    fn test_resampling() {
+        let hour = 3_600_000.0;
+
+        // 2023-03-01T23:30Z is already 2023-03-02 in UTC+01:00
+        let time = parseIsoTimestamp("2023-03-01T23:30:00Z").unwrap();
+        let localMidnight = parseIsoTimestamp("2023-03-02T00:00:00+01:00").unwrap();
+
+        assert_eq!(
+            bucketStart(time, "day", hour, 0.0, 0.0),
+            Some(localMidnight)
+        );
+        assert_eq!(
+            bucketStart(time, "month", 0.0, 0.0, 0.0),
+            parseIsoTimestamp("2023-03-01")
+        );
+        assert_eq!(
+            bucketStart(time, "shift", 0.0, 8.0 * hour, 6.0 * hour),
+            parseIsoTimestamp("2023-03-01T22:00:00Z")
+        );
+
+        let data = DataStruct {
+            groups: Some(vec![GroupItemStruct {
+                keyValues: None,
+                color: None,
+                stats: None,
+                values: Some(vec![1.0, 3.0, f64::NAN, 10.0]),
+                timestamps: Some(vec![
+                    TimestampEnum::Epoch(0.0),
+                    TimestampEnum::Epoch(0.5 * hour),
+                    TimestampEnum::Epoch(0.7 * hour),
+                    TimestampEnum::Epoch(1.5 * hour),
+                ]),
+            }]),
+            stats: None,
+            info: Some(InfoStruct {
+                HI_LIMIT: Some(5.0),
+                LO_LIMIT: Some(0.0),
+                UNITS: Some("V".to_string()),
+            }),
+        };
+
+        let config: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "resampleBucket": "hour", "resampleAggregation": "yield" }"#,
+        )
+        .unwrap();
+
+        let resampled = resampleData(data.clone(), &config).unwrap();
+        let group = &resampled.groups.unwrap()[0];
+
+        assert_eq!(group.values, Some(vec![100.0, 0.0]));
+        assert_eq!(
+            group.timestamps,
+            Some(vec![TimestampEnum::Epoch(0.0), TimestampEnum::Epoch(hour)])
+        );
+        assert_eq!(resampled.info.unwrap().UNITS, Some("%".to_string()));
+
+        // Shifts without length are rejected, a NaN offset leaves every value out
+        let zeroShift: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "resampleBucket": "shift", "shiftHours": 0 }"#,
+        )
+        .unwrap();
+
+        assert!(resampleData(data.clone(), &zeroShift).is_err());
+
+        // Misspelled names are errors instead of empty or NaN groups
+        let unknownBucket: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "resampleBucket": "hours" }"#)
+                .unwrap();
+
+        assert_eq!(
+            resampleData(data.clone(), &unknownBucket).unwrap_err(),
+            "Unknown resampleBucket: hours"
+        );
+
+        let unknownAggregation: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "resampleAggregation": "average" }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            resampleData(data.clone(), &unknownAggregation).unwrap_err(),
+            "Unknown resampleAggregation: average"
+        );
+
+        let nanOffset = ConfigStruct {
+            timezoneOffset: Some(f64::NAN),
+            ..config
+        };
+
+        assert_eq!(
+            resampleData(data, &nanOffset).unwrap().groups.unwrap()[0].values,
+            Some(vec![])
+        );
+    }
+
+    #[test]
+    fn test_autocorrelation() {
+        let (phi, pacf, _) = durbinLevinson(&[1.0, 0.5, 0.25], 2);
+
+        assert!((phi[0] - 0.5).abs() < 1e-12 && phi[1].abs() < 1e-12);
+        assert!((pacf[1] - 0.5).abs() < 1e-12 && pacf[2].abs() < 1e-12);
+
+        // Value repeats every 4 devices
+        let values: Vec<f64> = (0..32).map(|i| [1.0, 0.0, -1.0, 0.0][i % 4]).collect();
+
+        let analysis = autocorrelationAnalysis(&values, 8, 0.05);
+
+        assert_eq!(analysis.lags.len(), 9);
+        assert_eq!(analysis.acf[0], 1.0);
+        assert!(analysis.acf[4] > analysis.acfBounds[4]);
+        assert!(analysis.acf[2] < -analysis.acfBounds[2]);
+        assert_eq!(analysis.dominantPeriod, Some(4.0));
+        assert_eq!(analysis.frequencies.len(), 16);
+    }
+
+    #[test]
+    fn test_forecast() {
+        let group = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(vec![1.0, 2.1, 2.9, 4.0, 5.1, 5.9, 7.0]),
+            timestamps: None,
+        };
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "forecastHorizon": 3 }"#).unwrap();
+
+        let forecast = forecastGroup(&group, &config, Some(8.5), Some(0.0)).unwrap();
+
+        assert_eq!(forecast.model, "linear");
+        assert_eq!(forecast.x, vec![7.0, 8.0, 9.0]);
+        assert!((forecast.mean[0] - 8.0).abs() < 0.1);
+        assert!(
+            forecast.lower95[0] < forecast.lower80[0] && forecast.upper80[0] < forecast.upper95[0]
+        );
+        assert!(
+            forecast.upper95[2] - forecast.lower95[2] > forecast.upper95[0] - forecast.lower95[0]
+        );
+        assert_eq!(forecast.hiLimitCrossing, Some(8));
+        assert_eq!(forecast.loLimitCrossing, None);
+
+        for model in ["holt", "ar"] {
+            let config: ConfigStruct = serde_json::from_str(&format!(
+                r#"{{ "limitHighlight": false, "forecastHorizon": 3, "forecastModel": "{}" }}"#,
+                model
+            ))
+            .unwrap();
+
+            let forecast = forecastGroup(&group, &config, None, None).unwrap();
+
+            assert_eq!(forecast.mean.len(), 3);
+            assert!(forecast
+                .lower95
+                .iter()
+                .zip(forecast.upper95.iter())
+                .all(|(lo, hi)| lo <= hi));
+        }
+    }
+
+    #[test]
+    fn test_stl_decomposition() {
+        // Linear drift plus a cycle of 6 values and one spike
+        let mut values: Vec<f64> = (0..48)
+            .map(|i| 0.1 * i as f64 + [2.0, 1.0, 0.0, -2.0, -1.0, 0.0][i % 6])
+            .collect();
+        values[20] += 30.0;
+
+        let (trend, seasonal, residual) = stlDecomposition(&values, 6, 7, 5);
+
+        assert_eq!(trend.len(), 48);
+        assert!((0..48).all(|i| (trend[i] + seasonal[i] + residual[i] - values[i]).abs() < 1e-9));
+        assert!((seasonal[12] - 2.0).abs() < 0.3 && (seasonal[15] + 2.0).abs() < 0.3);
+        assert!((trend[30] - 3.0).abs() < 0.3);
+        assert!(residual[20] > 25.0);
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "stlPeriod": 30 }"#).unwrap();
+        let group = GroupItemStruct {
+            keyValues: None,
+            color: None,
+            stats: None,
+            values: Some(values),
+            timestamps: None,
+        };
+
+        assert_eq!(groupsDecomposition(vec![group], &config), vec![None]);
+    }
+
+    #[test]
+    fn test_parse_engineering() {
+        let (value, resolution, unit) = parseEngineering("\u{2212}440.9mV").unwrap();
+
+        assert!((value + 0.4409).abs() < 1e-12);
+        assert!((resolution - 1e-4).abs() < 1e-15);
+        assert_eq!(unit, "V");
+
+        assert_eq!(parseEngineering("834.7 uV").unwrap().2, "V");
+        assert!((parseEngineering("834.7µV").unwrap().0 - 834.7e-6).abs() < 1e-15);
+        assert_eq!(parseEngineering("1.5e3").unwrap().0, 1500.0);
+        assert_eq!(
+            parseEngineering("2k").unwrap(),
+            (2000.0, 1000.0, "".to_string())
+        );
+        assert_eq!(parseEngineering("79.9").unwrap().2, "");
+        assert_eq!(parseEngineering("n/a"), None);
+
+        let stats: StatsGroupStruct = serde_json::from_str(
+            r#"{ "Count": 53, "Cp": "79.9", "Cpk": "69.0", "Max": "−440.9mV", "Mean": "−442.7mV",
+                 "Min": "bad", "Std": "834.7µV", "cp": 79.87, "cpk": 12.0 }"#,
+        )
+        .unwrap();
+
+        let (stats, warnings) = fillGroupStats(stats, Some(2), None);
+
+        assert_eq!(stats.cp, Some(79.87));
+        assert!((stats.max.unwrap() + 0.4409).abs() < 1e-12);
+        assert_eq!(stats.min, None);
+        assert_eq!(warnings.len(), 2);
+        assert_eq!(warnings[0].field, "Cpk");
+        assert_eq!(warnings[0].group, Some(2));
+        assert_eq!(warnings[1].field, "Min");
+
+        // Strings are rescaled into UNITS, Cp stays a ratio
+        let stats: StatsGroupStruct = serde_json::from_str(
+            r#"{ "Cp": "1.5", "Max": "12.5", "Mean": "−440.9mV", "Min": "2.0A", "Std": "0.8347V",
+                 "mean": -440.9 }"#,
+        )
+        .unwrap();
+
+        let (stats, warnings) = fillGroupStats(stats, None, Some("mV"));
+
+        assert_eq!(stats.cp, Some(1.5));
+        assert_eq!(stats.max, Some(12.5));
+        assert_eq!(stats.mean, Some(-440.9));
+        assert!((stats.std.unwrap() - 834.7).abs() < 1e-9);
+        assert_eq!(stats.min, None);
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].field, "Min");
+        assert!(warnings[0].message.contains("differs from UNITS mV"));
+    }
+
+    #[test]
+    fn test_convert_units() {
+        assert_eq!(parseUnit("µA"), Ok((1e-6, "A")));
+        assert_eq!(parseUnit("kOhm"), Ok((1e3, "Ω")));
+        assert!(parseUnit("mdB").is_err());
+        assert!((conversionFactor("V", "mV").unwrap() - 1000.0).abs() < 1e-9);
+        assert!(conversionFactor("V", "A").is_err());
+
+        let data = DataStruct {
+            groups: Some(vec![GroupItemStruct {
+                keyValues: None,
+                color: None,
+                stats: Some(
+                    serde_json::from_str(r#"{ "Max": "2.0V", "cp": 1.5, "max": 2.0 }"#).unwrap(),
+                ),
+                values: Some(vec![1.0, 2.0]),
+                timestamps: None,
+            }]),
+            stats: None,
+            info: Some(InfoStruct {
+                HI_LIMIT: Some(2.5),
+                LO_LIMIT: None,
+                UNITS: Some("V".to_string()),
+            }),
+        };
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "displayUnit": "mV" }"#).unwrap();
+
+        let converted = convertData(data.clone(), &config).unwrap();
+        let group = &converted.groups.as_ref().unwrap()[0];
+        let info = converted.info.unwrap();
+
+        assert_eq!(group.values, Some(vec![1000.0, 2000.0]));
+        assert_eq!(group.stats.as_ref().unwrap().max, Some(2000.0));
+        assert_eq!(group.stats.as_ref().unwrap().cp, Some(1.5));
+        assert_eq!(info.HI_LIMIT, Some(2500.0));
+        assert_eq!(info.UNITS, Some("mV".to_string()));
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "displayUnit": "Hz" }"#).unwrap();
+
+        assert!(convertData(data, &config).is_err());
+    }
+
+    #[test]
+    fn test_validate_stats() {
+        let data = DataStruct {
+            groups: Some(vec![GroupItemStruct {
+                keyValues: None,
+                color: None,
+                stats: Some(
+                    serde_json::from_str(
+                        r#"{ "Count": 53, "cp": 1.0, "max": 4.0, "mean": 3.0, "min": 1.0, "std": 1.118 }"#,
+                    )
+                    .unwrap(),
+                ),
+                values: Some(vec![1.0, 2.0, f64::NAN, 3.0, 4.0]),
+                timestamps: None,
+            }]),
+            stats: Some(StatsStruct {
+                count: Some(4),
+                cp: None,
+                cpk: None,
+                max: Some(4.0),
+                mean: Some(2.5),
+                min: None,
+                std: Some(1.29),
+            }),
+            info: Some(InfoStruct {
+                HI_LIMIT: Some(5.0),
+                LO_LIMIT: Some(0.0),
+                UNITS: None,
+            }),
+        };
+
+        let config: ConfigStruct = serde_json::from_str(r#"{ "limitHighlight": false }"#).unwrap();
+
+        let warnings = validateData(&data, &config);
+        let fields: Vec<(Option<u32>, &str)> = warnings
+            .iter()
+            .map(|warning| (warning.group, warning.field.as_str()))
+            .collect();
+
+        // Population and sample std are both accepted, cp is (5 - 0) / (6 * 1.118) = 0.745
+        assert_eq!(
+            fields,
+            vec![(Some(0), "Count"), (Some(0), "cp"), (Some(0), "mean")]
+        );
+
+        // Counts are off by one well within the relative tolerance
+        let warnings = validateStats(
+            &[("count", Some(1001.0))],
+            &vec![1.0; 1000],
+            None,
+            None,
+            0.01,
+            None,
+        );
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].message, "count is 1001 but values imply 1000");
+    }
+
+    #[test]
+    fn test_assign_colors() {
+        let group = |site: Option<u32>, color: Option<&str>| GroupItemStruct {
+            keyValues: Some(KeyValuesStruct { SITE_NUM: site }),
+            color: color.map(|color| color.to_string()),
+            stats: None,
+            values: Some(vec![1.0]),
+            timestamps: None,
+        };
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "palette": "okabeIto" }"#).unwrap();
+
+        let groups = assignColors(
+            vec![
+                group(Some(3), None),
+                group(Some(1), Some("#123456")),
+                group(None, None),
+            ],
+            &config,
+        );
+
+        assert_eq!(groups[0].color, Some(PALETTE_OKABE_ITO[3].to_string()));
+        assert_eq!(groups[1].color, Some("#123456".to_string()));
+        assert_eq!(groups[2].color, Some(PALETTE_OKABE_ITO[2].to_string()));
+
+        // Site 3 keeps its colour whatever the other groups are
+        let groups = assignColors(vec![group(Some(3), None)], &config);
+
+        assert_eq!(groups[0].color, Some(PALETTE_OKABE_ITO[3].to_string()));
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "palette": "none" }"#).unwrap();
+
+        assert_eq!(
+            assignColors(vec![group(Some(3), None)], &config)[0].color,
+            None
+        );
+    }
+
+    #[test]
+    fn test_parse_color() {
+        // Same as parseGlColor of the front-end
+        assert_eq!(
+            parseColor("#3f1891"),
+            Ok([
+                0.24705882352941178,
+                0.09411764705882353,
+                0.5686274509803921,
+                1.0
+            ])
+        );
+        assert_eq!(parseColor("#f00"), Ok([1.0, 0.0, 0.0, 1.0]));
+        assert_eq!(parseColor("#00ff0080").unwrap()[3], 128.0 / 255.0);
+        assert_eq!(parseColor("rgba(255, 0, 0, 0.5)"), Ok([1.0, 0.0, 0.0, 0.5]));
+        assert_eq!(parseColor("rgb(0 0 255 / 50%)"), Ok([0.0, 0.0, 1.0, 0.5]));
+        assert_eq!(parseColor("RebeccaPurple"), Ok([0.4, 0.2, 0.6, 1.0]));
+
+        let [r, g, b, _] = parseColor("hsl(120, 100%, 25%)").unwrap();
+        assert!(r.abs() < 1e-12 && (g - 0.5).abs() < 1e-12 && b.abs() < 1e-12);
+
+        assert!(parseColor("#12345").is_err());
+        assert!(parseColor("rgb(1, 2)").is_err());
+        assert!(parseColor("bluish").is_err());
+
+        let groups: Vec<GroupItemStruct> = vec![
+            GroupItemStruct {
+                keyValues: None,
+                color: Some("bluish".to_string()),
+                stats: None,
+                values: Some(vec![1.0]),
+                timestamps: None,
+            },
+            GroupItemStruct {
+                keyValues: None,
+                color: Some("red".to_string()),
+                stats: None,
+                values: Some(vec![1.0]),
+                timestamps: None,
+            },
+        ];
+
+        assert_eq!(
+            groupsGlColors(groups.clone()),
+            vec![DEFAULT_GL_COLOR, [1.0, 0.0, 0.0, 1.0]]
+        );
+
+        let warnings = colorWarnings(&groups);
+
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(
+            (warnings[0].group, warnings[0].field.as_str()),
+            (Some(0), "color")
+        );
+    }
+
+    #[test]
+    fn test_tooltip_rows() {
+        assert_eq!(formatEngineering(-0.4409, "V"), "\u{2212}440.9mV");
+        assert_eq!(formatEngineering(0.00083468, "V"), "834.7µV");
+        assert_eq!(formatEngineering(999.96, "Hz"), "1.000kHz");
+        assert_eq!(formatEngineering(0.0, ""), "0.000");
+
+        let group = GroupItemStruct {
+            keyValues: Some(KeyValuesStruct { SITE_NUM: Some(3) }),
+            color: None,
+            stats: Some(
+                serde_json::from_str(r#"{ "Cp": "79.9", "cpk": 68.96, "max": -0.44 }"#).unwrap(),
+            ),
+            values: Some(vec![-0.441, -0.447]),
+            timestamps: None,
+        };
+        let info = InfoStruct {
+            HI_LIMIT: Some(-0.442),
+            LO_LIMIT: Some(-0.4467),
+            UNITS: Some("V".to_string()),
+        };
+
+        let config: ConfigStruct = serde_json::from_str(r#"{ "limitHighlight": false }"#).unwrap();
+        let rows = groupTooltip(&group, 1, &info, &config);
+        let names: Vec<&str> = rows.iter().map(|row| row.name.as_str()).collect();
+
+        assert_eq!(
+            names,
+            vec![
+                "SITE_NUM",
+                "Value",
+                "Cp",
+                "Cpk",
+                "Max",
+                "Mean",
+                "Min",
+                "Std",
+                "Margin",
+                "Violations"
+            ]
+        );
+        assert!(rows[0].isFromData);
+        assert_eq!(rows[1].value, "\u{2212}447.0mV");
+        assert_eq!(rows[2].value, "79.9");
+        assert_eq!(rows[3].value, "69.0");
+        assert_eq!(rows[4].value, "\u{2212}440.0mV");
+        assert_eq!(rows[5].value, "");
+        assert_eq!(rows[8].value, "\u{2212}300.0µV");
+        assert_eq!(rows[9].value, "Below LO_LIMIT");
+
+        let config: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "tooltipFields": ["Violations", "Value"] }"#,
+        )
+        .unwrap();
+        let rows = groupTooltip(&group, 0, &info, &config);
+
+        assert_eq!(rows.len(), 2);
+        assert_eq!(rows[0].value, "Above HI_LIMIT");
+        assert!(groupTooltip(&group, 5, &info, &config).is_empty());
+    }
+
+    #[test]
+    fn test_render_svg() {
+        assert_eq!(niceTicks(0.0, 10.0, 5), vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+        assert_eq!(niceTicks(-0.35, 0.05, 4), vec![-0.3, -0.2, -0.1, 0.0]);
+        assert_eq!(
+            formatTimeTick(DAY_MS + 1.5 * HOUR_MS, HOUR_MS, 0.0),
+            "01-02 01:30"
+        );
+
+        let data: DataStruct = serde_json::from_str(
+            r##"{
+                "groups": [
+                    { "keyValues": { "SITE_NUM": 1 }, "color": "#3f1891", "values": [1.0, 2.0, 3.0] },
+                    { "keyValues": { "SITE_NUM": 2 }, "values": [2.0, 1.0] }
+                ],
+                "info": { "HI_LIMIT": 2.5, "LO_LIMIT": 0.5, "UNITS": "V" }
+            }"##,
+        )
+        .unwrap();
+        let config: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": true, "drawLines": true, "marker": true, "markerSize": 20, "width": 400, "height": 200 }"#,
+        )
+        .unwrap();
+
+        let scene = plotScene(&process(data.clone(), &config).unwrap(), &config);
+        let count = |name: &str| {
+            scene
+                .plot
+                .iter()
+                .filter(|shape| match shape {
+                    ShapeEnum::Circle { filled, .. } => name == "circle" && *filled,
+                    ShapeEnum::Polyline { .. } => name == "polyline",
+                    ShapeEnum::Hatch { .. } => name == "hatch",
+                    _ => false,
+                })
+                .count()
+        };
+
+        assert_eq!(
+            (count("circle"), count("polyline"), count("hatch")),
+            (5, 2, 2)
+        );
+
+        let svg = renderSvg(data, &config).unwrap();
+
+        assert!(svg.starts_with("<svg") && svg.ends_with("</svg>"));
+        assert!(svg.contains(r#"width="400" height="200""#));
+        assert!(svg.contains(r#"fill="url(#hatch-"#));
+        assert!(svg.contains("3.000V"));
+    }
+
+    #[test]
+    fn test_render_png() {
+        let data: DataStruct = serde_json::from_str(
+            r##"{
+                "groups": [
+                    { "keyValues": { "SITE_NUM": 1 }, "color": "#0000ff", "values": [1.0, 2.0, 3.0] }
+                ],
+                "info": { "HI_LIMIT": 2.5, "LO_LIMIT": 0.5, "UNITS": "V" }
+            }"##,
+        )
+        .unwrap();
+        let config: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": true, "marker": true, "markerSize": 80, "width": 200, "height": 100, "dpi": 192 }"#,
+        )
+        .unwrap();
+
+        let bytes = renderPng(data.clone(), &config).unwrap();
+        let decoder = png::Decoder::new(bytes.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+
+        assert_eq!((reader.info().width, reader.info().height), (400, 200));
+        assert_eq!(reader.info().pixel_dims.unwrap().xppu, 7559);
+
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+
+        let pixel = |x: f64, y: f64| {
+            let i = 4 * ((y * 2.0) as usize * 400 + (x * 2.0) as usize);
+            [pixels[i], pixels[i + 1], pixels[i + 2]]
+        };
+
+        // Middle marker takes the group colour, margins stay white
+        let scene = plotScene(&process(data.clone(), &config).unwrap(), &config);
+        let centers: Vec<(f64, f64)> = scene
+            .plot
+            .iter()
+            .filter_map(|shape| match shape {
+                ShapeEnum::Circle { center, .. } => Some(*center),
+                _ => None,
+            })
+            .collect();
+
+        assert_eq!(pixel(centers[1].0, centers[1].1), [0, 0, 255]);
+        assert_eq!(pixel(2.0, 2.0), [255, 255, 255]);
+
+        let zeroDpi: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "dpi": 0 }"#).unwrap();
+        assert!(renderPng(
+            DataStruct {
+                groups: None,
+                stats: None,
+                info: None
+            },
+            &zeroDpi
+        )
+        .is_err());
+
+        // 800x400 at 100 times 96 dpi would be 3.2 gigapixels, refused before allocating
+        let hugeDpi: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "dpi": 9600 }"#).unwrap();
+        assert!(renderPng(data.clone(), &hugeDpi)
+            .unwrap_err()
+            .contains("pixels"));
+
+        let hugeSize: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "width": 100000, "height": 100000 }"#,
+        )
+        .unwrap();
+        assert!(renderPng(data, &hugeSize).is_err());
+    }
+
+    #[test]
+    fn test_render_unit_glyphs() {
+        let render = |text: &str| {
+            let scene = SceneStruct {
+                width: 20.0,
+                height: 20.0,
+                plotArea: ((0.0, 0.0), (20.0, 20.0)),
+                plot: vec![],
+                axes: vec![ShapeEnum::Text {
+                    position: (2.0, 12.0),
+                    text: text.to_string(),
+                    anchor: "start".to_string(),
+                    color: "#000000".to_string(),
+                    size: 10.0,
+                }],
+            };
+
+            sceneToPixmap(&scene, 1.0).unwrap().data().to_vec()
+        };
+
+        // Every non-ASCII symbol of unit labels has its own glyph
+        let unknown = render("?");
+        let label = "\u{2212}1µV 2μA 3Ω 4\u{2126}";
+
+        for character in label.chars().filter(|character| !character.is_ascii()) {
+            assert_ne!(render(&character.to_string()), unknown, "{}", character);
+        }
+
+        assert_eq!(render("Ω"), render("\u{2126}"));
+        assert_eq!(render("µ"), render("μ"));
+        assert_eq!(render("\u{2212}"), render("-"));
+    }
+
+    #[test]
+    fn test_summary() {
+        let groups: Vec<GroupItemStruct> = serde_json::from_str(
+            r#"[
+                { "keyValues": { "SITE_NUM": 1 }, "values": [1.0, 2.0, 3.0, 4.0] },
+                { "keyValues": { "SITE_NUM": 2 }, "values": [] }
+            ]"#,
+        )
+        .unwrap();
+
+        let stats = groupsStats(groups.clone(), Some(0.0), Some(3.5));
+
+        assert_eq!(stats[0].stats.mean, Some(2.5));
+        assert!((stats[0].stats.std.unwrap() - 1.25f64.sqrt()).abs() < 1e-12);
+        assert!((stats[0].stats.cpk.unwrap() - 1.0 / (3.0 * 1.25f64.sqrt())).abs() < 1e-12);
+        assert_eq!((stats[1].stats.count, stats[1].stats.mean), (Some(0), None));
+
+        let summary = yieldSummary(groups, Some(1.5), Some(3.5));
+
+        assert_eq!(
+            (
+                summary.groups[0].passed,
+                summary.groups[0].belowLimit,
+                summary.groups[0].aboveLimit
+            ),
+            (2, 1, 1)
+        );
+        assert_eq!(summary.groups[1].yieldPercent, None);
+        assert_eq!(
+            (summary.total.count, summary.total.yieldPercent),
+            (4, Some(50.0))
+        );
+
+        // Inverted limits count values on both sides, nothing passes
+        let inverted = valuesYield(&[1.0, 2.0, 3.0], Some(2.5), Some(1.5), None);
+
+        assert_eq!(
+            (inverted.passed, inverted.belowLimit, inverted.aboveLimit),
+            (0, 2, 2)
+        );
+        assert_eq!(inverted.yieldPercent, Some(0.0));
+
+        // Stats of the CLI and the bindings come from statsGroups, like the box plots
+        let data: DataStruct = serde_json::from_str(
+            r#"{
+                "groups": [{ "keyValues": { "SITE_NUM": 1 }, "values": [1.0, 2.0, 3.0, 4.0, 100.0] }],
+                "info": { "HI_LIMIT": 3.5, "LO_LIMIT": 0.0 }
+            }"#,
+        )
+        .unwrap();
+        let config: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "outlierMethod": "iqr", "excludeOutliers": true }"#,
+        )
+        .unwrap();
+
+        let result = process(data.clone(), &config).unwrap();
+        let stats = groupsStats(result.statsGroups.clone(), Some(0.0), Some(3.5));
+        let summary = yieldSummary(result.statsGroups, Some(0.0), Some(3.5));
+
+        assert_eq!(
+            (stats[0].stats.count, stats[0].stats.mean),
+            (Some(4), Some(2.5))
+        );
+        assert_eq!(summary.total.yieldPercent, Some(75.0));
+
+        let config: ConfigStruct =
+            serde_json::from_str(r#"{ "limitHighlight": false, "outlierMethod": "iqr" }"#).unwrap();
+
+        let result = process(data, &config).unwrap();
+
+        assert_eq!(
+            yieldSummary(result.statsGroups, Some(0.0), Some(3.5))
+                .total
+                .yieldPercent,
+            Some(60.0)
+        );
+    }
+
+    #[test]
+    fn test_read_csv() {
+        // Wide with a decimal comma, header rows and a quoted field holding the delimiter
+        let wide = "\u{feff};Site 1;Site 2\nHI_LIMIT;\"3,5\";3,5\nUNITS;V;V\n;1,25;2\n;\"-0,5\";\n";
+
+        let data = readCsv(wide, &ConfigStruct::default()).unwrap();
+        let groups = data.groups.unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1].keyValues.unwrap().SITE_NUM, Some(2));
+        assert_eq!(groups[0].values, Some(vec![1.25, -0.5]));
+        assert_eq!(groups[1].values, Some(vec![2.0]));
+        assert_eq!(data.info.as_ref().unwrap().HI_LIMIT, Some(3.5));
+        assert_eq!(data.info.unwrap().UNITS, Some("V".to_string()));
+
+        // Unnamed columns take free site numbers, two columns of one site are an error
+        let groups = readCsv("A,Site 1\n1,2\n", &ConfigStruct::default())
+            .unwrap()
+            .groups
+            .unwrap();
+
+        assert_eq!(
+            groups
+                .iter()
+                .map(|group| group.keyValues.unwrap().SITE_NUM)
+                .collect::<Vec<Option<u32>>>(),
+            vec![Some(2), Some(1)]
+        );
+        assert!(readCsv("Site 1,S1\n1,2\n", &ConfigStruct::default()).is_err());
+
+        // Long grouped by lot, limits from config win over the LO_LIMIT column
+        let long = "SITE_NUM,LOT,TIME,VALUE,LO_LIMIT\n1,A,0,1.5,0\n1,B,1,\"2.5\",0\n1,A,2,3,0\n";
+        let config: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "csvFormat": "long",
+                 "csvKeyColumns": ["LOT"], "csvTimestampColumn": "time",
+                 "csvLoLimit": -1.0 }"#,
+        )
+        .unwrap();
+
+        let data = readCsv(long, &config).unwrap();
+        let groups = data.groups.unwrap();
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[1].keyValues.unwrap().SITE_NUM, Some(2));
+        assert_eq!(groups[0].values, Some(vec![1.5, 3.0]));
+        assert_eq!(
+            groups[0].timestamps,
+            Some(vec![TimestampEnum::Epoch(0.0), TimestampEnum::Epoch(2.0)])
+        );
+        assert_eq!(data.info.unwrap().LO_LIMIT, Some(-1.0));
+
+        // Lots of one site would be groups with the same SITE_NUM
+        let siteAndLot: ConfigStruct = serde_json::from_str(
+            r#"{ "limitHighlight": false, "csvFormat": "long", "csvKeyColumns": ["SITE_NUM", "LOT"] }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            readCsv(long, &siteAndLot).unwrap_err(),
+            "Duplicate SITE_NUM 1 in CSV: key 1/A and key 1/B"
+        );
+
+        assert_eq!(detectDelimiter("a\tb,c\n"), '\t');
+        assert!(readCsv("SITE_NUM\n\"1\n", &config).is_err());
+        assert!(readCsv("A,B\nx,1\n", &ConfigStruct::default()).is_err());
+    }
+}
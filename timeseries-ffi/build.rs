@@ -0,0 +1,22 @@
+#![allow(non_snake_case)]
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Generate timeseries.h from the extern "C" functions of the crate into OUT_DIR,
+ * the tests check include/timeseries.h against it since cargo must not write to the sources
+ * @method main
+ */
+fn main() {
+    let crateDir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let outDir = std::env::var("OUT_DIR").unwrap();
+
+    println!("cargo:rerun-if-changed=src/lib.rs");
+    println!("cargo:rerun-if-changed=cbindgen.toml");
+
+    let config = cbindgen::Config::from_file(format!("{}/cbindgen.toml", crateDir)).unwrap();
+
+    cbindgen::generate_with_config(&crateDir, config)
+        .expect("Unable to generate timeseries.h")
+        .write_to_file(format!("{}/timeseries.h", outDir));
+}
@@ -0,0 +1,225 @@
+#ifndef TIMESERIES_H
+#define TIMESERIES_H
+
+/* Generated by cbindgen from timeseries-ffi, do not edit */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/*
+ Version of the C ABI, bumped on every incompatible change of timeseries.h
+ */
+#define TS_ABI_VERSION 1
+
+/*
+ Site argument selecting every site
+ */
+#define TS_ALL_SITES UINT32_MAX
+
+/*
+ Status of a call, ts_last_error describes failures
+ */
+typedef enum TsStatus {
+  TS_STATUS_OK = 0,
+  TS_STATUS_NULL_POINTER = 1,
+  TS_STATUS_UNKNOWN_SITE = 2,
+  TS_STATUS_INVALID_ARGUMENT = 3,
+  TS_STATUS_PROCESSING_FAILED = 4,
+  TS_STATUS_INTERNAL_ERROR = 5,
+} TsStatus;
+
+/*
+ Rule broken by a value
+ */
+typedef enum TsRule {
+  TS_RULE_ABOVE_HI_LIMIT = 0,
+  TS_RULE_BELOW_LO_LIMIT = 1,
+  TS_RULE_OUTLIER = 2,
+  TS_RULE_EWMA_OUT_OF_CONTROL = 3,
+  TS_RULE_CUSUM_OUT_OF_CONTROL = 4,
+} TsRule;
+
+/*
+ Values of every site with limits and config, owned by the caller until ts_dataset_free
+ */
+typedef struct TsDataset TsDataset;
+
+/*
+ Stats and yield of values, NaN when a stat is not defined
+ */
+typedef struct TsStats {
+  uint32_t count;
+  double mean;
+  double std;
+  double min;
+  double max;
+  double cp;
+  double cpk;
+  uint32_t passed;
+  uint32_t below_limit;
+  uint32_t above_limit;
+  double yield_percent;
+} TsStats;
+
+/*
+ Value breaking a rule, index counts the values appended to its site, NaN included
+ */
+typedef struct TsViolation {
+  uint32_t site;
+  uint32_t index;
+  enum TsRule rule;
+} TsViolation;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/*
+ * Get version of the C ABI the library implements
+ * @method ts_abi_version
+ * @returns {number} - TS_ABI_VERSION
+ */
+uint32_t ts_abi_version(void);
+
+/*
+ * Get message of the last failed call of the current thread
+ * @method ts_last_error
+ * @returns {string} - owned by the library, valid until the next failed call of the thread
+ */
+const char *ts_last_error(void);
+
+/*
+ * Create an empty dataset
+ * @method ts_dataset_new
+ * @param {number} lo_limit - NaN for no limit
+ * @param {number} hi_limit - NaN for no limit
+ * @param {string} units - UTF-8, may be null
+ * @returns {object} - free with ts_dataset_free, null when units is not UTF-8 or lo_limit is above hi_limit
+ */
+struct TsDataset *ts_dataset_new(double lo_limit,
+                                 double hi_limit,
+                                 const char *units);
+
+/*
+ * Free a dataset created by ts_dataset_new
+ * @method ts_dataset_free
+ * @param {object} dataset - may be null
+ */
+void ts_dataset_free(struct TsDataset *dataset);
+
+/*
+ * Replace the limits of a dataset
+ * @method ts_dataset_set_limits
+ * @param {object} dataset
+ * @param {number} lo_limit - NaN for no limit
+ * @param {number} hi_limit - NaN for no limit
+ * @returns {number} - status, InvalidArgument when lo_limit is above hi_limit
+ */
+enum TsStatus ts_dataset_set_limits(struct TsDataset *dataset, double lo_limit, double hi_limit);
+
+/*
+ * Get the limits of a dataset
+ * @method ts_dataset_limits
+ * @param {object} dataset
+ * @param {number} lo_limit - written, NaN for no limit
+ * @param {number} hi_limit - written, NaN for no limit
+ * @returns {number} - status
+ */
+enum TsStatus ts_dataset_limits(const struct TsDataset *dataset,
+                                double *lo_limit,
+                                double *hi_limit);
+
+/*
+ * Replace the config of a dataset, it picks the outlier and control chart rules
+ * @method ts_dataset_set_config
+ * @param {object} dataset
+ * @param {string} config_json - UTF-8 JSON in the ConfigStruct schema, limitHighlight may be left out
+ * @returns {number} - status
+ */
+enum TsStatus ts_dataset_set_config(struct TsDataset *dataset,
+                                    const char *config_json);
+
+/*
+ * Append values measured on a site, the site is created on its first values
+ * @method ts_dataset_append
+ * @param {object} dataset
+ * @param {number} site - any number but TS_ALL_SITES
+ * @param {array} values - count values, copied
+ * @param {number} count
+ * @returns {number} - status
+ */
+enum TsStatus ts_dataset_append(struct TsDataset *dataset,
+                                uint32_t site,
+                                const double *values,
+                                uintptr_t count);
+
+/*
+ * Get the sites of a dataset in order of their first values
+ * @method ts_dataset_sites
+ * @param {object} dataset
+ * @param {array} sites - written up to capacity sites, may be null when capacity is 0
+ * @param {number} capacity
+ * @param {number} count - written, number of sites of the dataset
+ * @returns {number} - status
+ */
+enum TsStatus ts_dataset_sites(const struct TsDataset *dataset,
+                               uint32_t *sites,
+                               uintptr_t capacity,
+                               uintptr_t *count);
+
+/*
+ * Compute stats and yield of a site or of every site together
+ * @method ts_dataset_stats
+ * @param {object} dataset
+ * @param {number} site - TS_ALL_SITES for every site
+ * @param {object} stats - written
+ * @returns {number} - status
+ */
+enum TsStatus ts_dataset_stats(const struct TsDataset *dataset,
+                               uint32_t site,
+                               struct TsStats *stats);
+
+/*
+ * Get the rules broken by the values of a site or of every site
+ * @method ts_dataset_violations
+ * @param {object} dataset
+ * @param {number} site - TS_ALL_SITES for every site
+ * @param {array} violations - written, free with ts_violations_free, null when there is none
+ * @param {number} count - written
+ * @returns {number} - status
+ */
+enum TsStatus ts_dataset_violations(const struct TsDataset *dataset,
+                                    uint32_t site,
+                                    struct TsViolation **violations,
+                                    uintptr_t *count);
+
+/*
+ * Free violations returned by ts_dataset_violations
+ * @method ts_violations_free
+ * @param {array} violations - may be null
+ * @param {number} count - count returned with violations
+ */
+void ts_violations_free(struct TsViolation *violations, uintptr_t count);
+
+/*
+ * Run the pipeline of the plot on the dataset
+ * @method ts_dataset_result_json
+ * @param {object} dataset
+ * @returns {string} - UTF-8 JSON of ReturnValuesStruct, free with ts_string_free, null on failure
+ */
+char *ts_dataset_result_json(const struct TsDataset *dataset);
+
+/*
+ * Free a string returned by the library
+ * @method ts_string_free
+ * @param {string} text - may be null
+ */
+void ts_string_free(char *text);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* TIMESERIES_H */
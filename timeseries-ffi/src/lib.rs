@@ -0,0 +1,705 @@
+#![allow(non_snake_case)]
+// Every pointer is checked for null, the others must come from this library
+// or point to as many elements as their count argument says
+#![allow(clippy::missing_safety_doc)]
+
+/* -------------- Import Modules -------------- */
+
+use std::{
+    cell::RefCell,
+    ffi::{c_char, CStr, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr, slice,
+};
+use timeseries_core::{
+    consts::*, data_preprocess::*, pipeline::*, structs::*, summary::*, tooltip::*,
+};
+
+/* -------------- Define structs -------------- */
+
+/// Version of the C ABI, bumped on every incompatible change of timeseries.h
+pub const TS_ABI_VERSION: u32 = 1;
+
+/// Site argument selecting every site
+pub const TS_ALL_SITES: u32 = u32::MAX;
+
+/// Status of a call, ts_last_error describes failures
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TsStatus {
+    Ok = 0,
+    NullPointer = 1,
+    UnknownSite = 2,
+    InvalidArgument = 3,
+    ProcessingFailed = 4,
+    InternalError = 5,
+}
+
+/// Rule broken by a value
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum TsRule {
+    AboveHiLimit = 0,
+    BelowLoLimit = 1,
+    Outlier = 2,
+    EwmaOutOfControl = 3,
+    CusumOutOfControl = 4,
+}
+
+/// Stats and yield of values, NaN when a stat is not defined
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TsStats {
+    pub count: u32,
+    pub mean: f64,
+    pub std: f64,
+    pub min: f64,
+    pub max: f64,
+    pub cp: f64,
+    pub cpk: f64,
+    pub passed: u32,
+    pub below_limit: u32,
+    pub above_limit: u32,
+    pub yield_percent: f64,
+}
+
+/// Value breaking a rule, index counts the values appended to its site, NaN included
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct TsViolation {
+    pub site: u32,
+    pub index: u32,
+    pub rule: TsRule,
+}
+
+/// Values of every site with limits and config, owned by the caller until ts_dataset_free
+pub struct TsDataset {
+    groups: Vec<GroupItemStruct>,
+    info: InfoStruct,
+    config: ConfigStruct,
+}
+
+thread_local! {
+    static LAST_ERROR: RefCell<CString> = RefCell::new(CString::default());
+}
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Remember error message of the current thread
+ * @method fail
+ * @param {number} status
+ * @param {string} message
+ * @returns {number} - status
+ */
+fn fail(status: TsStatus, message: &str) -> TsStatus {
+    LAST_ERROR.with(|error| {
+        *error.borrow_mut() = CString::new(message.replace('\0', " ")).unwrap_or_default();
+    });
+
+    status
+}
+
+/**
+ * Run the body of an entry point, a panic is reported as InternalError instead of unwinding into C
+ * @method guard
+ * @param {object} fallback - returned when body panics
+ * @param {function} body
+ * @returns {object}
+ */
+fn guard<T>(fallback: T, body: impl FnOnce() -> T) -> T {
+    match panic::catch_unwind(AssertUnwindSafe(body)) {
+        Ok(value) => value,
+        Err(payload) => {
+            let message = payload
+                .downcast_ref::<&str>()
+                .map(|message| message.to_string())
+                .or_else(|| payload.downcast_ref::<String>().cloned())
+                .unwrap_or("unknown panic".to_string());
+
+            fail(
+                TsStatus::InternalError,
+                &format!("internal error: {}", message),
+            );
+
+            fallback
+        }
+    }
+}
+
+/**
+ * Check limits of a C argument, NaN means no limit
+ * @method checkLimits
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {array} - [loLimit, hiLimit], error when loLimit is above hiLimit
+ */
+fn checkLimits(loLimit: f64, hiLimit: f64) -> Result<(Option<f64>, Option<f64>), String> {
+    let (loLimit, hiLimit) = (optionalLimit(loLimit), optionalLimit(hiLimit));
+
+    match (loLimit, hiLimit) {
+        (Some(lo), Some(hi)) if lo > hi => Err(format!("lo_limit {} is above hi_limit {}", lo, hi)),
+        _ => Ok((loLimit, hiLimit)),
+    }
+}
+
+/**
+ * Get limit of a C argument, NaN means no limit
+ * @method optionalLimit
+ * @param {number} value
+ * @returns {number}
+ */
+fn optionalLimit(value: f64) -> Option<f64> {
+    Some(value).filter(|value| !value.is_nan())
+}
+
+/**
+ * Compute stats and yield of values against the limits of info
+ * @method valuesTsStats
+ * @param {array} values - NaN is left out
+ * @param {object} info
+ * @returns {object}
+ */
+fn valuesTsStats(values: &[f64], info: &InfoStruct) -> TsStats {
+    let finiteValues: Vec<f64> = values.iter().copied().filter(|v| !v.is_nan()).collect();
+    let stats = valuesStats(&finiteValues, info.LO_LIMIT, info.HI_LIMIT);
+    let counts = valuesYield(&finiteValues, info.LO_LIMIT, info.HI_LIMIT, None);
+
+    TsStats {
+        count: counts.count,
+        mean: stats.mean.unwrap_or(f64::NAN),
+        std: stats.std.unwrap_or(f64::NAN),
+        min: stats.min.unwrap_or(f64::NAN),
+        max: stats.max.unwrap_or(f64::NAN),
+        cp: stats.cp.unwrap_or(f64::NAN),
+        cpk: stats.cpk.unwrap_or(f64::NAN),
+        passed: counts.passed,
+        below_limit: counts.belowLimit,
+        above_limit: counts.aboveLimit,
+        yield_percent: counts.yieldPercent.unwrap_or(f64::NAN),
+    }
+}
+
+/**
+ * Get values of a site
+ * @method siteValues
+ * @param {object} dataset
+ * @param {number} site
+ * @returns {array} - null when the site has no values
+ */
+fn siteValues(dataset: &TsDataset, site: u32) -> Option<&Vec<f64>> {
+    dataset
+        .groups
+        .iter()
+        .find(|group| group.keyValues.and_then(|keyValues| keyValues.SITE_NUM) == Some(site))
+        .and_then(|group| group.values.as_ref())
+}
+
+/**
+ * Get version of the C ABI the library implements
+ * @method ts_abi_version
+ * @returns {number} - TS_ABI_VERSION
+ */
+#[no_mangle]
+pub extern "C" fn ts_abi_version() -> u32 {
+    guard(TS_ABI_VERSION, || TS_ABI_VERSION)
+}
+
+/**
+ * Get message of the last failed call of the current thread
+ * @method ts_last_error
+ * @returns {string} - owned by the library, valid until the next failed call of the thread
+ */
+#[no_mangle]
+pub extern "C" fn ts_last_error() -> *const c_char {
+    guard(ptr::null(), || {
+        LAST_ERROR.with(|error| error.borrow().as_ptr())
+    })
+}
+
+/**
+ * Create an empty dataset
+ * @method ts_dataset_new
+ * @param {number} lo_limit - NaN for no limit
+ * @param {number} hi_limit - NaN for no limit
+ * @param {string} units - UTF-8, may be null
+ * @returns {object} - free with ts_dataset_free, null when units is not UTF-8 or lo_limit is above hi_limit
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_new(
+    lo_limit: f64,
+    hi_limit: f64,
+    units: *const c_char,
+) -> *mut TsDataset {
+    guard(ptr::null_mut(), || {
+        let units = if units.is_null() {
+            None
+        } else {
+            match CStr::from_ptr(units).to_str() {
+                Ok(units) => Some(units.to_string()),
+                Err(_) => {
+                    fail(TsStatus::InvalidArgument, "units is not UTF-8");
+                    return ptr::null_mut();
+                }
+            }
+        };
+
+        let (LO_LIMIT, HI_LIMIT) = match checkLimits(lo_limit, hi_limit) {
+            Ok(limits) => limits,
+            Err(message) => {
+                fail(TsStatus::InvalidArgument, &message);
+                return ptr::null_mut();
+            }
+        };
+
+        let dataset = TsDataset {
+            groups: Vec::new(),
+            info: InfoStruct {
+                HI_LIMIT,
+                LO_LIMIT,
+                UNITS: units,
+            },
+            config: ConfigStruct::default(),
+        };
+
+        Box::into_raw(Box::new(dataset))
+    })
+}
+
+/**
+ * Free a dataset created by ts_dataset_new
+ * @method ts_dataset_free
+ * @param {object} dataset - may be null
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_free(dataset: *mut TsDataset) {
+    guard((), || {
+        if !dataset.is_null() {
+            drop(Box::from_raw(dataset));
+        }
+    })
+}
+
+/**
+ * Replace the limits of a dataset
+ * @method ts_dataset_set_limits
+ * @param {object} dataset
+ * @param {number} lo_limit - NaN for no limit
+ * @param {number} hi_limit - NaN for no limit
+ * @returns {number} - status, InvalidArgument when lo_limit is above hi_limit
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_set_limits(
+    dataset: *mut TsDataset,
+    lo_limit: f64,
+    hi_limit: f64,
+) -> TsStatus {
+    guard(TsStatus::InternalError, || {
+        let dataset = match dataset.as_mut() {
+            Some(dataset) => dataset,
+            None => return fail(TsStatus::NullPointer, "dataset is null"),
+        };
+
+        let (LO_LIMIT, HI_LIMIT) = match checkLimits(lo_limit, hi_limit) {
+            Ok(limits) => limits,
+            Err(message) => return fail(TsStatus::InvalidArgument, &message),
+        };
+
+        dataset.info.LO_LIMIT = LO_LIMIT;
+        dataset.info.HI_LIMIT = HI_LIMIT;
+
+        TsStatus::Ok
+    })
+}
+
+/**
+ * Get the limits of a dataset
+ * @method ts_dataset_limits
+ * @param {object} dataset
+ * @param {number} lo_limit - written, NaN for no limit
+ * @param {number} hi_limit - written, NaN for no limit
+ * @returns {number} - status
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_limits(
+    dataset: *const TsDataset,
+    lo_limit: *mut f64,
+    hi_limit: *mut f64,
+) -> TsStatus {
+    guard(TsStatus::InternalError, || {
+        let dataset = match dataset.as_ref() {
+            Some(dataset) => dataset,
+            None => return fail(TsStatus::NullPointer, "dataset is null"),
+        };
+
+        if lo_limit.is_null() || hi_limit.is_null() {
+            return fail(TsStatus::NullPointer, "limit output is null");
+        }
+
+        *lo_limit = dataset.info.LO_LIMIT.unwrap_or(f64::NAN);
+        *hi_limit = dataset.info.HI_LIMIT.unwrap_or(f64::NAN);
+
+        TsStatus::Ok
+    })
+}
+
+/**
+ * Replace the config of a dataset, it picks the outlier and control chart rules
+ * @method ts_dataset_set_config
+ * @param {object} dataset
+ * @param {string} config_json - UTF-8 JSON in the ConfigStruct schema, limitHighlight may be left out
+ * @returns {number} - status
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_set_config(
+    dataset: *mut TsDataset,
+    config_json: *const c_char,
+) -> TsStatus {
+    guard(TsStatus::InternalError, || {
+        let dataset = match dataset.as_mut() {
+            Some(dataset) => dataset,
+            None => return fail(TsStatus::NullPointer, "dataset is null"),
+        };
+
+        if config_json.is_null() {
+            return fail(TsStatus::NullPointer, "config_json is null");
+        }
+
+        let mut value: serde_json::Value = match CStr::from_ptr(config_json)
+            .to_str()
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str(text).map_err(|e| e.to_string()))
+        {
+            Ok(value) => value,
+            Err(message) => return fail(TsStatus::InvalidArgument, &message),
+        };
+
+        if let Some(object) = value.as_object_mut() {
+            object
+                .entry("limitHighlight")
+                .or_insert(serde_json::Value::Bool(false));
+        }
+
+        match serde_json::from_value(value) {
+            Ok(config) => {
+                dataset.config = config;
+
+                TsStatus::Ok
+            }
+            Err(e) => fail(TsStatus::InvalidArgument, &e.to_string()),
+        }
+    })
+}
+
+/**
+ * Append values measured on a site, the site is created on its first values
+ * @method ts_dataset_append
+ * @param {object} dataset
+ * @param {number} site - any number but TS_ALL_SITES
+ * @param {array} values - count values, copied
+ * @param {number} count
+ * @returns {number} - status
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_append(
+    dataset: *mut TsDataset,
+    site: u32,
+    values: *const f64,
+    count: usize,
+) -> TsStatus {
+    guard(TsStatus::InternalError, || {
+        let dataset = match dataset.as_mut() {
+            Some(dataset) => dataset,
+            None => return fail(TsStatus::NullPointer, "dataset is null"),
+        };
+
+        if site == TS_ALL_SITES {
+            return fail(TsStatus::InvalidArgument, "TS_ALL_SITES can't hold values");
+        }
+        if values.is_null() && count > 0 {
+            return fail(TsStatus::NullPointer, "values is null");
+        }
+
+        let newValues: &[f64] = if count == 0 {
+            &[]
+        } else {
+            slice::from_raw_parts(values, count)
+        };
+
+        let position = dataset.groups.iter().position(|group| {
+            group.keyValues.and_then(|keyValues| keyValues.SITE_NUM) == Some(site)
+        });
+
+        match position {
+            Some(i) => dataset.groups[i]
+                .values
+                .get_or_insert_with(Vec::new)
+                .extend_from_slice(newValues),
+            None => dataset.groups.push(GroupItemStruct {
+                keyValues: Some(KeyValuesStruct {
+                    SITE_NUM: Some(site),
+                }),
+                color: None,
+                stats: None,
+                values: Some(newValues.to_vec()),
+                timestamps: None,
+            }),
+        }
+
+        TsStatus::Ok
+    })
+}
+
+/**
+ * Get the sites of a dataset in order of their first values
+ * @method ts_dataset_sites
+ * @param {object} dataset
+ * @param {array} sites - written up to capacity sites, may be null when capacity is 0
+ * @param {number} capacity
+ * @param {number} count - written, number of sites of the dataset
+ * @returns {number} - status
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_sites(
+    dataset: *const TsDataset,
+    sites: *mut u32,
+    capacity: usize,
+    count: *mut usize,
+) -> TsStatus {
+    guard(TsStatus::InternalError, || {
+        let dataset = match dataset.as_ref() {
+            Some(dataset) => dataset,
+            None => return fail(TsStatus::NullPointer, "dataset is null"),
+        };
+
+        if count.is_null() || (sites.is_null() && capacity > 0) {
+            return fail(TsStatus::NullPointer, "sites output is null");
+        }
+
+        let siteNumbers: Vec<u32> = dataset
+            .groups
+            .iter()
+            .filter_map(|group| group.keyValues.and_then(|keyValues| keyValues.SITE_NUM))
+            .collect();
+
+        for (i, site) in siteNumbers.iter().take(capacity).enumerate() {
+            *sites.add(i) = *site;
+        }
+
+        *count = siteNumbers.len();
+
+        TsStatus::Ok
+    })
+}
+
+/**
+ * Compute stats and yield of a site or of every site together
+ * @method ts_dataset_stats
+ * @param {object} dataset
+ * @param {number} site - TS_ALL_SITES for every site
+ * @param {object} stats - written
+ * @returns {number} - status
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_stats(
+    dataset: *const TsDataset,
+    site: u32,
+    stats: *mut TsStats,
+) -> TsStatus {
+    guard(TsStatus::InternalError, || {
+        let dataset = match dataset.as_ref() {
+            Some(dataset) => dataset,
+            None => return fail(TsStatus::NullPointer, "dataset is null"),
+        };
+
+        if stats.is_null() {
+            return fail(TsStatus::NullPointer, "stats is null");
+        }
+
+        let values: Vec<f64> = if site == TS_ALL_SITES {
+            dataset
+                .groups
+                .iter()
+                .flat_map(|group| group.values.clone().unwrap_or(vec![]))
+                .collect()
+        } else {
+            match siteValues(dataset, site) {
+                Some(values) => values.clone(),
+                None => {
+                    return fail(
+                        TsStatus::UnknownSite,
+                        &format!("site {} has no values", site),
+                    )
+                }
+            }
+        };
+
+        *stats = valuesTsStats(&values, &dataset.info);
+
+        TsStatus::Ok
+    })
+}
+
+/**
+ * Get the rules broken by the values of a site or of every site
+ * @method ts_dataset_violations
+ * @param {object} dataset
+ * @param {number} site - TS_ALL_SITES for every site
+ * @param {array} violations - written, free with ts_violations_free, null when there is none
+ * @param {number} count - written
+ * @returns {number} - status
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_violations(
+    dataset: *const TsDataset,
+    site: u32,
+    violations: *mut *mut TsViolation,
+    count: *mut usize,
+) -> TsStatus {
+    guard(TsStatus::InternalError, || {
+        let dataset = match dataset.as_ref() {
+            Some(dataset) => dataset,
+            None => return fail(TsStatus::NullPointer, "dataset is null"),
+        };
+
+        if violations.is_null() || count.is_null() {
+            return fail(TsStatus::NullPointer, "violations output is null");
+        }
+        if site != TS_ALL_SITES && siteValues(dataset, site).is_none() {
+            return fail(
+                TsStatus::UnknownSite,
+                &format!("site {} has no values", site),
+            );
+        }
+
+        let mut found: Vec<TsViolation> = Vec::new();
+
+        for group in dataset.groups.iter() {
+            let groupSite = group
+                .keyValues
+                .and_then(|keyValues| keyValues.SITE_NUM)
+                .unwrap_or(TS_ALL_SITES);
+
+            if site != TS_ALL_SITES && groupSite != site {
+                continue;
+            }
+
+            // Rules see the values the pipeline sees, NaN left out
+            let filterGroups = filterGroup(vec![group.clone()], vec![]);
+            let valueIndexes: Vec<usize> = group
+                .values
+                .iter()
+                .flatten()
+                .enumerate()
+                .filter(|(_, value)| !value.is_nan())
+                .map(|(index, _)| index)
+                .collect();
+
+            let groupRules = groupViolations(
+                &filterGroups[0],
+                &dataset.config,
+                dataset.info.LO_LIMIT,
+                dataset.info.HI_LIMIT,
+            );
+
+            for (index, rules) in groupRules.iter().enumerate() {
+                for rule in rules {
+                    let rule = match rule.as_str() {
+                        RULE_ABOVE_HI_LIMIT => TsRule::AboveHiLimit,
+                        RULE_BELOW_LO_LIMIT => TsRule::BelowLoLimit,
+                        RULE_OUTLIER => TsRule::Outlier,
+                        RULE_EWMA => TsRule::EwmaOutOfControl,
+                        RULE_CUSUM => TsRule::CusumOutOfControl,
+                        _ => continue,
+                    };
+
+                    found.push(TsViolation {
+                        site: groupSite,
+                        index: valueIndexes[index] as u32,
+                        rule,
+                    });
+                }
+            }
+        }
+
+        *count = found.len();
+        *violations = if found.is_empty() {
+            ptr::null_mut()
+        } else {
+            Box::into_raw(found.into_boxed_slice()) as *mut TsViolation
+        };
+
+        TsStatus::Ok
+    })
+}
+
+/**
+ * Free violations returned by ts_dataset_violations
+ * @method ts_violations_free
+ * @param {array} violations - may be null
+ * @param {number} count - count returned with violations
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_violations_free(violations: *mut TsViolation, count: usize) {
+    guard((), || {
+        if !violations.is_null() {
+            drop(Box::from_raw(ptr::slice_from_raw_parts_mut(
+                violations, count,
+            )));
+        }
+    })
+}
+
+/**
+ * Run the pipeline of the plot on the dataset
+ * @method ts_dataset_result_json
+ * @param {object} dataset
+ * @returns {string} - UTF-8 JSON of ReturnValuesStruct, free with ts_string_free, null on failure
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_dataset_result_json(dataset: *const TsDataset) -> *mut c_char {
+    guard(ptr::null_mut(), || {
+        let dataset = match dataset.as_ref() {
+            Some(dataset) => dataset,
+            None => {
+                fail(TsStatus::NullPointer, "dataset is null");
+                return ptr::null_mut();
+            }
+        };
+
+        let data = DataStruct {
+            groups: Some(dataset.groups.clone()),
+            stats: None,
+            info: Some(dataset.info.clone()),
+        };
+
+        let text = process(data, &dataset.config)
+            .and_then(|result| serde_json::to_string(&result).map_err(|e| e.to_string()));
+
+        match text.map(CString::new) {
+            Ok(Ok(text)) => text.into_raw(),
+            Ok(Err(e)) => {
+                fail(TsStatus::ProcessingFailed, &e.to_string());
+                ptr::null_mut()
+            }
+            Err(message) => {
+                fail(TsStatus::ProcessingFailed, &message);
+                ptr::null_mut()
+            }
+        }
+    })
+}
+
+/**
+ * Free a string returned by the library
+ * @method ts_string_free
+ * @param {string} text - may be null
+ */
+#[no_mangle]
+pub unsafe extern "C" fn ts_string_free(text: *mut c_char) {
+    guard((), || {
+        if !text.is_null() {
+            drop(CString::from_raw(text));
+        }
+    })
+}
@@ -0,0 +1,194 @@
+#![allow(non_snake_case)]
+
+use std::{ffi::CStr, ptr};
+use timeseries_ffi::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_c_abi() {
+        unsafe {
+            let dataset = ts_dataset_new(1.5, 3.5, c"V".as_ptr());
+            let site1 = [1.0, 2.0, f64::NAN, 3.0, 4.0];
+            let site2 = [2.0, 2.5];
+
+            assert_eq!(
+                ts_dataset_append(dataset, 1, site1.as_ptr(), 5),
+                TsStatus::Ok
+            );
+            assert_eq!(
+                ts_dataset_append(dataset, 2, site2.as_ptr(), 2),
+                TsStatus::Ok
+            );
+            assert_eq!(ts_dataset_append(dataset, 2, ptr::null(), 0), TsStatus::Ok);
+
+            let mut sites = [0u32; 1];
+            let mut count = 0;
+            assert_eq!(
+                ts_dataset_sites(dataset, sites.as_mut_ptr(), 1, &mut count),
+                TsStatus::Ok
+            );
+            assert_eq!((sites, count), ([1], 2));
+
+            let mut stats = std::mem::zeroed::<TsStats>();
+            assert_eq!(ts_dataset_stats(dataset, 1, &mut stats), TsStatus::Ok);
+            assert_eq!(
+                (
+                    stats.count,
+                    stats.passed,
+                    stats.below_limit,
+                    stats.above_limit
+                ),
+                (4, 2, 1, 1)
+            );
+            assert_eq!(stats.yield_percent, 50.0);
+
+            assert_eq!(
+                ts_dataset_stats(dataset, TS_ALL_SITES, &mut stats),
+                TsStatus::Ok
+            );
+            assert_eq!(stats.count, 6);
+
+            assert_eq!(
+                ts_dataset_stats(dataset, 9, &mut stats),
+                TsStatus::UnknownSite
+            );
+            assert_eq!(
+                CStr::from_ptr(ts_last_error()).to_str().unwrap(),
+                "site 9 has no values"
+            );
+
+            let mut violations: *mut TsViolation = ptr::null_mut();
+            assert_eq!(
+                ts_dataset_violations(dataset, TS_ALL_SITES, &mut violations, &mut count),
+                TsStatus::Ok
+            );
+            let found = std::slice::from_raw_parts(violations, count).to_vec();
+            ts_violations_free(violations, count);
+
+            assert_eq!(
+                found,
+                vec![
+                    TsViolation {
+                        site: 1,
+                        index: 0,
+                        rule: TsRule::BelowLoLimit
+                    },
+                    TsViolation {
+                        site: 1,
+                        index: 4,
+                        rule: TsRule::AboveHiLimit
+                    },
+                ]
+            );
+
+            // Inverted limits are rejected and the old ones kept
+            assert_eq!(
+                ts_dataset_set_limits(dataset, 5.0, 1.0),
+                TsStatus::InvalidArgument
+            );
+            assert_eq!(
+                CStr::from_ptr(ts_last_error()).to_str().unwrap(),
+                "lo_limit 5 is above hi_limit 1"
+            );
+            assert_eq!(ts_dataset_stats(dataset, 1, &mut stats), TsStatus::Ok);
+            assert_eq!(stats.passed, 2);
+            assert!(ts_dataset_new(5.0, 1.0, ptr::null()).is_null());
+
+            // Without limits nothing is violated
+            assert_eq!(
+                ts_dataset_set_limits(dataset, f64::NAN, f64::NAN),
+                TsStatus::Ok
+            );
+            let (mut lo, mut hi) = (0.0, 0.0);
+            assert_eq!(ts_dataset_limits(dataset, &mut lo, &mut hi), TsStatus::Ok);
+            assert!(lo.is_nan() && hi.is_nan());
+            assert_eq!(
+                ts_dataset_violations(dataset, 2, &mut violations, &mut count),
+                TsStatus::Ok
+            );
+            assert_eq!((violations, count), (ptr::null_mut(), 0));
+
+            assert_eq!(
+                ts_dataset_set_config(dataset, c"{ \"outlierMethod\": 3 }".as_ptr()),
+                TsStatus::InvalidArgument
+            );
+            assert_eq!(
+                ts_dataset_set_config(dataset, c"{ \"outlierMethod\": \"iqr\" }".as_ptr()),
+                TsStatus::Ok
+            );
+
+            let json = ts_dataset_result_json(dataset);
+            assert!(CStr::from_ptr(json)
+                .to_str()
+                .unwrap()
+                .starts_with("{\"filterGroups\""));
+            ts_string_free(json);
+
+            assert_eq!(
+                ts_dataset_stats(ptr::null(), 1, &mut stats),
+                TsStatus::NullPointer
+            );
+            ts_dataset_free(dataset);
+        }
+    }
+
+    #[test]
+    fn test_c_abi_nan_violations() {
+        unsafe {
+            let dataset = ts_dataset_new(f64::NAN, f64::NAN, ptr::null());
+            let values = [10.0, 11.0, 9.0, 10.0, 11.0, 9.0, 10.0, f64::NAN, 30.0];
+
+            assert_eq!(
+                ts_dataset_append(dataset, 1, values.as_ptr(), values.len()),
+                TsStatus::Ok
+            );
+            assert_eq!(
+                ts_dataset_set_config(
+                    dataset,
+                    c"{ \"controlCharts\": true, \"controlBaseline\": 7 }".as_ptr()
+                ),
+                TsStatus::Ok
+            );
+
+            let mut violations: *mut TsViolation = ptr::null_mut();
+            let mut count = 0;
+            assert_eq!(
+                ts_dataset_violations(dataset, 1, &mut violations, &mut count),
+                TsStatus::Ok
+            );
+            let found = std::slice::from_raw_parts(violations, count).to_vec();
+            ts_violations_free(violations, count);
+
+            // The NaN doesn't stop the charts and the index still counts it
+            assert_eq!(
+                found,
+                vec![
+                    TsViolation {
+                        site: 1,
+                        index: 8,
+                        rule: TsRule::EwmaOutOfControl
+                    },
+                    TsViolation {
+                        site: 1,
+                        index: 8,
+                        rule: TsRule::CusumOutOfControl
+                    },
+                ]
+            );
+            ts_dataset_free(dataset);
+        }
+    }
+
+    #[test]
+    fn test_header_up_to_date() {
+        // Regenerate with: cbindgen --config cbindgen.toml --output include/timeseries.h
+        assert_eq!(
+            include_str!("../include/timeseries.h"),
+            include_str!(concat!(env!("OUT_DIR"), "/timeseries.h")),
+            "include/timeseries.h is out of date"
+        );
+    }
+}
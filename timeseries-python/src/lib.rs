@@ -0,0 +1,327 @@
+#![allow(non_snake_case)]
+
+/* -------------- Import Modules -------------- */
+
+use numpy::{PyArray1, PyReadonlyArray1};
+use pyo3::{
+    exceptions::PyValueError,
+    prelude::*,
+    types::{PyDict, PyList},
+};
+use serde::{de::DeserializeOwned, Serialize};
+use timeseries_core::{csv_import::*, data_preprocess::*, pipeline::*, structs::*, summary::*};
+
+/* -------------- Define functions -------------- */
+
+/**
+ * Convert Python object to a core struct through JSON, numpy arrays and scalars become lists and numbers
+ * @method fromPython
+ * @param {object} value
+ * @returns {object} - ValueError when value doesn't match the struct
+ */
+fn fromPython<T: DeserializeOwned>(value: &Bound<'_, PyAny>) -> PyResult<T> {
+    let py = value.py();
+    let kwargs = PyDict::new(py);
+
+    kwargs.set_item(
+        "default",
+        py.eval(c"lambda value: value.tolist()", None, None)?,
+    )?;
+
+    let text: String = py
+        .import("json")?
+        .call_method("dumps", (value,), Some(&kwargs))?
+        .extract()?;
+
+    serde_json::from_str(&text).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/**
+ * Convert core struct to Python dicts and lists through JSON
+ * @method toPython
+ * @param {object} value
+ * @returns {object} - NaN becomes None
+ */
+fn toPython<'py, T: Serialize>(py: Python<'py>, value: &T) -> PyResult<Bound<'py, PyAny>> {
+    let text = serde_json::to_string(value).map_err(|e| PyValueError::new_err(e.to_string()))?;
+
+    py.import("json")?.call_method1("loads", (text,))
+}
+
+/**
+ * Get values from a numpy array or a sequence of numbers, NaN is kept
+ * @method valuesFromPython
+ * @param {object} values
+ * @returns {array}
+ */
+fn valuesFromPython(values: &Bound<'_, PyAny>) -> PyResult<Vec<f64>> {
+    // An array can only exist once numpy is imported, lists work without numpy
+    let numpyImported = values
+        .py()
+        .import("sys")?
+        .getattr("modules")?
+        .contains("numpy")?;
+
+    if numpyImported {
+        if let Ok(array) = values.extract::<PyReadonlyArray1<f64>>() {
+            return Ok(array.as_array().to_vec());
+        }
+    }
+
+    values.extract()
+}
+
+/**
+ * Get data from a dict in the DataStruct schema, group values may be numpy arrays
+ * @method dataFromPython
+ * @param {object} data
+ * @returns {object}
+ */
+fn dataFromPython(data: &Bound<'_, PyAny>) -> PyResult<DataStruct> {
+    let data = data.cast::<PyDict>()?;
+
+    // Values go around JSON, it has no NaN
+    let mut groupsValues: Vec<Option<Vec<f64>>> = Vec::new();
+    let rest = data.copy()?;
+
+    if let Some(items) = data.get_item("groups")? {
+        let groupsWithoutValues = PyList::empty(data.py());
+
+        for item in items.try_iter()? {
+            let group = item?.cast_into::<PyDict>()?.copy()?;
+
+            groupsValues.push(match group.get_item("values")? {
+                Some(values) if !values.is_none() => Some(valuesFromPython(&values)?),
+                _ => None,
+            });
+            group.del_item("values").ok();
+            groupsWithoutValues.append(group)?;
+        }
+
+        rest.set_item("groups", groupsWithoutValues)?;
+    }
+
+    let data: DataStruct = fromPython(rest.as_any())?;
+
+    Ok(DataStruct {
+        groups: data.groups.map(|groups| {
+            groups
+                .into_iter()
+                .zip(groupsValues)
+                .map(|(group, values)| GroupItemStruct { values, ..group })
+                .collect()
+        }),
+        ..data
+    })
+}
+
+/**
+ * Get config from a dict in the ConfigStruct schema, limitHighlight defaults to false
+ * @method configFromPython
+ * @param {object} config - None for the defaults
+ * @returns {object}
+ */
+fn configFromPython(config: Option<&Bound<'_, PyAny>>) -> PyResult<ConfigStruct> {
+    let mut value: serde_json::Value = match config {
+        Some(config) => fromPython(config)?,
+        None => serde_json::json!({}),
+    };
+
+    if let Some(object) = value.as_object_mut() {
+        object
+            .entry("limitHighlight")
+            .or_insert(serde_json::Value::Bool(false));
+    }
+
+    serde_json::from_value(value).map_err(|e| PyValueError::new_err(e.to_string()))
+}
+
+/**
+ * Run the whole pipeline of the plot
+ * @method pyProcess
+ * @param {object} data - dict in the DataStruct schema
+ * @param {object} config - dict in the ConfigStruct schema
+ * @returns {object} - dict of ReturnValuesStruct
+ */
+#[pyfunction]
+#[pyo3(name = "process", signature = (data, config=None))]
+pub fn pyProcess<'py>(
+    data: &Bound<'py, PyAny>,
+    config: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let result = process(dataFromPython(data)?, &configFromPython(config)?)
+        .map_err(PyValueError::new_err)?;
+
+    toPython(data.py(), &result)
+}
+
+/**
+ * Get visible groups
+ * @method pyFilterGroup
+ * @param {object} data - dict in the DataStruct schema
+ * @param {array} hiddenGroups - indexes of hidden groups
+ * @returns {array} - list of group dicts
+ */
+#[pyfunction]
+#[pyo3(name = "filter_group", signature = (data, hidden_groups=None))]
+pub fn pyFilterGroup<'py>(
+    data: &Bound<'py, PyAny>,
+    hidden_groups: Option<Vec<u32>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let groups = dataFromPython(data)?.groups.unwrap_or(vec![]);
+
+    toPython(
+        data.py(),
+        &filterGroup(groups, hidden_groups.unwrap_or(vec![])),
+    )
+}
+
+/**
+ * Get points of the plot as columns, ready for a DataFrame
+ * @method pyPreprocess
+ * @param {object} data - dict in the DataStruct schema
+ * @param {object} config - dict in the ConfigStruct schema
+ * @returns {object} - dict of x, y, value and outlier arrays, site and color lists
+ */
+#[pyfunction]
+#[pyo3(name = "preprocess", signature = (data, config=None))]
+pub fn pyPreprocess<'py>(
+    data: &Bound<'py, PyAny>,
+    config: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyDict>> {
+    let py = data.py();
+    let points = process(dataFromPython(data)?, &configFromPython(config)?)
+        .map_err(PyValueError::new_err)?
+        .preprocessGroups;
+
+    let column =
+        |get: fn(&PreprocessItemStruct) -> f64| -> Vec<f64> { points.iter().map(get).collect() };
+
+    let columns = PyDict::new(py);
+
+    columns.set_item("x", PyArray1::from_vec(py, column(|point| point.x)))?;
+    columns.set_item("y", PyArray1::from_vec(py, column(|point| point.y)))?;
+    columns.set_item("value", PyArray1::from_vec(py, column(|point| point.value)))?;
+    columns.set_item(
+        "outlier",
+        PyArray1::from_vec(py, points.iter().map(|point| point.outlier).collect()),
+    )?;
+    columns.set_item(
+        "site",
+        points
+            .iter()
+            .map(|point| point.keyValues.SITE_NUM)
+            .collect::<Vec<Option<u32>>>(),
+    )?;
+    columns.set_item(
+        "color",
+        points
+            .iter()
+            .map(|point| point.color.clone())
+            .collect::<Vec<String>>(),
+    )?;
+
+    Ok(columns)
+}
+
+/**
+ * Compute stats of values like the stats of the dashboard, NaN is left out
+ * @method pyStats
+ * @param {array} values - numpy array or sequence of numbers
+ * @param {number} loLimit
+ * @param {number} hiLimit
+ * @returns {object} - dict of StatsStruct
+ */
+#[pyfunction]
+#[pyo3(name = "stats", signature = (values, lo_limit=None, hi_limit=None))]
+pub fn pyStats<'py>(
+    values: &Bound<'py, PyAny>,
+    lo_limit: Option<f64>,
+    hi_limit: Option<f64>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let finiteValues: Vec<f64> = valuesFromPython(values)?
+        .into_iter()
+        .filter(|value| !value.is_nan())
+        .collect();
+
+    toPython(values.py(), &valuesStats(&finiteValues, lo_limit, hi_limit))
+}
+
+/**
+ * Get x and y domain of the plot
+ * @method pyDomain
+ * @param {object} data - dict in the DataStruct schema
+ * @param {object} config - dict in the ConfigStruct schema
+ * @returns {array} - ((x0, x1), (y0, y1))
+ */
+#[pyfunction]
+#[pyo3(name = "domain", signature = (data, config=None))]
+pub fn pyDomain(
+    data: &Bound<'_, PyAny>,
+    config: Option<&Bound<'_, PyAny>>,
+) -> PyResult<((f64, f64), (f64, f64))> {
+    let ([x0, x1], [y0, y1]) = process(dataFromPython(data)?, &configFromPython(config)?)
+        .map_err(PyValueError::new_err)?
+        .domain;
+
+    Ok(((x0, x1), (y0, y1)))
+}
+
+/**
+ * Count values inside and beyond the limits for every visible group and in total, outliers are
+ * left out with excludeOutliers
+ * @method pyYieldSummary
+ * @param {object} data - dict in the DataStruct schema
+ * @param {object} config - dict in the ConfigStruct schema
+ * @returns {object} - dict of YieldSummaryStruct
+ */
+#[pyfunction]
+#[pyo3(name = "yield_summary", signature = (data, config=None))]
+pub fn pyYieldSummary<'py>(
+    data: &Bound<'py, PyAny>,
+    config: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let result = process(dataFromPython(data)?, &configFromPython(config)?)
+        .map_err(PyValueError::new_err)?;
+
+    toPython(
+        data.py(),
+        &yieldSummary(
+            result.statsGroups,
+            result.info.LO_LIMIT,
+            result.info.HI_LIMIT,
+        ),
+    )
+}
+
+/**
+ * Read CSV or TSV text into data, the result can be passed to process
+ * @method pyReadCsv
+ * @param {string} text
+ * @param {object} config - dict with the csv options of the ConfigStruct schema
+ * @returns {object} - dict in the DataStruct schema
+ */
+#[pyfunction]
+#[pyo3(name = "read_csv", signature = (text, config=None))]
+pub fn pyReadCsv<'py>(
+    py: Python<'py>,
+    text: &str,
+    config: Option<&Bound<'py, PyAny>>,
+) -> PyResult<Bound<'py, PyAny>> {
+    let data = readCsv(text, &configFromPython(config)?).map_err(PyValueError::new_err)?;
+
+    toPython(py, &data)
+}
+
+#[pymodule]
+fn timeseries(module: &Bound<'_, PyModule>) -> PyResult<()> {
+    module.add_function(wrap_pyfunction!(pyProcess, module)?)?;
+    module.add_function(wrap_pyfunction!(pyFilterGroup, module)?)?;
+    module.add_function(wrap_pyfunction!(pyPreprocess, module)?)?;
+    module.add_function(wrap_pyfunction!(pyStats, module)?)?;
+    module.add_function(wrap_pyfunction!(pyDomain, module)?)?;
+    module.add_function(wrap_pyfunction!(pyYieldSummary, module)?)?;
+    module.add_function(wrap_pyfunction!(pyReadCsv, module)?)?;
+
+    Ok(())
+}
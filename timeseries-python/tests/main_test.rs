@@ -0,0 +1,150 @@
+#![allow(non_snake_case)]
+
+use numpy::PyReadonlyArray1;
+use pyo3::{prelude::*, types::PyDict};
+use timeseries::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_python_bindings() {
+        Python::attach(|py| {
+            let data = py
+                .eval(
+                    c"{'groups': [{'keyValues': {'SITE_NUM': 1}, 'values': [1.0, float('nan'), 3.0, 4.0]}], 'info': {'HI_LIMIT': 3.5, 'LO_LIMIT': 1.5}}",
+                    None,
+                    None,
+                )
+                .unwrap();
+
+            let groups = pyFilterGroup(&data, Some(vec![0])).unwrap();
+            assert_eq!(groups.len().unwrap(), 0);
+
+            let result = pyProcess(&data, None).unwrap();
+            let info = result.get_item("info").unwrap();
+            assert_eq!(
+                info.get_item("HI_LIMIT").unwrap().extract::<f64>().unwrap(),
+                3.5
+            );
+
+            // NaN stays in the values, it is left out of the stats
+            let values = data
+                .get_item("groups")
+                .unwrap()
+                .get_item(0)
+                .unwrap()
+                .get_item("values")
+                .unwrap();
+            let stats = pyStats(&values, Some(1.5), Some(3.5)).unwrap();
+            assert_eq!(
+                stats.get_item("count").unwrap().extract::<u32>().unwrap(),
+                3
+            );
+
+            let summary = pyYieldSummary(&data, None).unwrap();
+            let total = summary.get_item("total").unwrap();
+            assert_eq!(
+                total.get_item("passed").unwrap().extract::<u32>().unwrap(),
+                1
+            );
+
+            let config = PyDict::new(py);
+            config.set_item("hiddenGroups", vec![0]).unwrap();
+            assert!(pyDomain(&data, Some(config.as_any())).is_ok());
+
+            // Outliers are left out of the yield like the box plots with excludeOutliers
+            let data = py
+                .eval(
+                    c"{'groups': [{'keyValues': {'SITE_NUM': 1}, 'values': [1.0, 2.0, 3.0, 4.0, 100.0]}], 'info': {'HI_LIMIT': 3.5, 'LO_LIMIT': 0.0}}",
+                    None,
+                    None,
+                )
+                .unwrap();
+            let config = PyDict::new(py);
+            config.set_item("outlierMethod", "iqr").unwrap();
+            config.set_item("excludeOutliers", true).unwrap();
+
+            let summary = pyYieldSummary(&data, Some(config.as_any())).unwrap();
+            let total = summary.get_item("total").unwrap();
+            assert_eq!(
+                total.get_item("count").unwrap().extract::<u32>().unwrap(),
+                4
+            );
+        });
+    }
+
+    #[test]
+    fn test_python_numpy() {
+        Python::attach(|py| {
+            // numpy is optional for the bindings, without it there is nothing to check
+            let Ok(np) = py.import("numpy") else {
+                eprintln!("numpy is not installed, skipping");
+                return;
+            };
+            let globals = PyDict::new(py);
+            globals.set_item("np", &np).unwrap();
+
+            // Every other value of an arange, a strided view and not a contiguous array
+            let data = py
+                .eval(
+                    c"{'groups': [{'keyValues': {'SITE_NUM': 1}, 'values': np.arange(10, dtype=np.float64)[::2]}], 'info': {'HI_LIMIT': 7.0, 'LO_LIMIT': 1.0}}",
+                    Some(&globals),
+                    None,
+                )
+                .unwrap();
+            let values = data
+                .get_item("groups")
+                .unwrap()
+                .get_item(0)
+                .unwrap()
+                .get_item("values")
+                .unwrap();
+
+            assert!(!values
+                .getattr("flags")
+                .unwrap()
+                .get_item("C_CONTIGUOUS")
+                .unwrap()
+                .extract::<bool>()
+                .unwrap());
+
+            let ndarray = np.getattr("ndarray").unwrap();
+            let columns = pyPreprocess(&data, None).unwrap();
+            let column = |name: &str| {
+                let column = columns.get_item(name).unwrap().unwrap();
+                assert!(column.is_instance(&ndarray).unwrap());
+                column
+                    .extract::<PyReadonlyArray1<f64>>()
+                    .unwrap()
+                    .as_array()
+                    .to_vec()
+            };
+
+            assert_eq!(column("x"), vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+            assert_eq!(column("y"), vec![0.0, 2.0, 4.0, 6.0, 8.0]);
+            assert_eq!(column("value"), vec![0.0, 2.0, 4.0, 6.0, 8.0]);
+
+            let result = pyProcess(&data, None).unwrap();
+            assert_eq!(
+                result
+                    .get_item("groupsValues")
+                    .unwrap()
+                    .extract::<Vec<Vec<f64>>>()
+                    .unwrap(),
+                vec![vec![0.0, 2.0, 4.0, 6.0, 8.0]]
+            );
+
+            let stats = pyStats(&values, Some(1.0), Some(7.0)).unwrap();
+            assert_eq!(
+                stats.get_item("count").unwrap().extract::<u32>().unwrap(),
+                5
+            );
+            assert_eq!(
+                stats.get_item("mean").unwrap().extract::<f64>().unwrap(),
+                4.0
+            );
+        });
+    }
+}
@@ -1,3 +0,0 @@
-/* -------------- Define constants -------------- */
-
-pub const DEFAULT_COLOR: &str = "#27ae60";
@@ -2,12 +2,10 @@
 
 /* -------------- Import Modules -------------- */
 
-pub mod consts;
-pub mod data_preprocess;
-pub mod structs;
-pub mod utils;
-
-use crate::{data_preprocess::*, structs::*, utils::*};
+use timeseries_core::{
+    consts::*, csv_import::*, data_preprocess::*, pipeline::*, raster::*, render::*, resampling::*,
+    rolling_stats::*, structs::*, timestamps::*, tooltip::*, utils,
+};
 
 use serde::Serialize;
 use wasm_bindgen::prelude::*;
@@ -39,39 +37,11 @@ pub fn console_log<T: Serialize + ?Sized>(str: &str, value: &T) {
 pub fn main(input_data: JsValue, input_config: JsValue) -> JsValue {
     let data: DataStruct = serde_wasm_bindgen::from_value(input_data).unwrap();
 
-    // Get groups from data and validate it
-    let groups = data.groups.unwrap_or(vec![]);
-
     let config: ConfigStruct = serde_wasm_bindgen::from_value(input_config).unwrap();
 
-    // let hiddenGroups = config.hiddenGroups;
-    let hiddenGroups = config.hiddenGroups.unwrap_or(vec![]);
-
-    let filterGroups = filterGroup(groups, hiddenGroups);
-
-    let preprocessGroups = preprocess(filterGroups.clone());
-
-    let groupsValues = groupsValuesList(filterGroups.clone());
-
-    let domain = getDomain(preprocessGroups.clone());
-
-    let info = data.info.unwrap_or(InfoStruct {
-        HI_LIMIT: None,
-        LO_LIMIT: None,
-        UNITS: None,
-    });
-
-    let HI_LIMIT = info.HI_LIMIT.unwrap_or(0.0);
-    let LO_LIMIT = info.LO_LIMIT.unwrap_or(0.0);
-
-    let shouldShowLimit = shouldShowLimit(HI_LIMIT, LO_LIMIT);
-
-    let returnValues = ReturnValuesStruct {
-        filterGroups,
-        preprocessGroups,
-        groupsValues,
-        domain,
-        shouldShowLimit,
+    let returnValues = match process(data, &config) {
+        Ok(returnValues) => returnValues,
+        Err(message) => wasm_bindgen::throw_str(&message),
     };
 
     console_log(
@@ -100,11 +70,133 @@ pub fn main(input_data: JsValue, input_config: JsValue) -> JsValue {
  */
 #[wasm_bindgen]
 pub fn checkApprox(point: Option<f64>, coord: Option<f64>, epsilon: Option<f64>) -> bool {
-    let verifiedPoint = point.unwrap_or(0.0).abs();
-    let verifiedCoord = coord.unwrap_or(0.0).abs();
-    let verifiedEpsilon = epsilon.unwrap_or(0.0001).abs();
+    utils::checkApprox(point, coord, epsilon)
+}
 
-    let approx = (verifiedPoint - verifiedCoord).abs() <= verifiedEpsilon;
+/**
+ * Get rolling mean, std, min, max and band of values, NaN values are skipped
+ * @method rollingStats
+ * @param {array} values
+ * @param {number} window - number of values in the window, NaN included
+ * @param {number} k - band half width in std
+ * @returns {object}
+ */
+#[wasm_bindgen]
+pub fn rollingStats(values: Vec<f64>, window: u32, k: Option<f64>) -> JsValue {
+    let rolling = rollingStatistics(&values, window as usize, k.unwrap_or(DEFAULT_ROLLING_K));
+
+    serde_wasm_bindgen::to_value(&rolling).unwrap()
+}
+
+/**
+ * Aggregate data into time buckets, the result can be passed to main
+ * @method resample
+ * @param {object} input_data
+ * @param {object} input_config
+ * @returns {object}
+ */
+#[wasm_bindgen]
+pub fn resample(input_data: JsValue, input_config: JsValue) -> JsValue {
+    let data: DataStruct = serde_wasm_bindgen::from_value(input_data).unwrap();
+    let config: ConfigStruct = serde_wasm_bindgen::from_value(input_config).unwrap();
+
+    match resampleData(data, &config) {
+        Ok(data) => serde_wasm_bindgen::to_value(&data).unwrap(),
+        Err(message) => wasm_bindgen::throw_str(&message),
+    }
+}
+
+/**
+ * Get tooltip rows of a hit
+ * @method tooltip
+ * @param {object} input_data
+ * @param {object} input_config
+ * @param {number} group - index of the group in data
+ * @param {number} index - index of the value in the group, NaN values left out
+ * @returns {array} - empty when the hit is out of data
+ */
+#[wasm_bindgen]
+pub fn tooltip(input_data: JsValue, input_config: JsValue, group: u32, index: u32) -> JsValue {
+    let data: DataStruct = serde_wasm_bindgen::from_value(input_data).unwrap();
+    let config: ConfigStruct = serde_wasm_bindgen::from_value(input_config).unwrap();
+
+    // Same stats, units and colours as main
+    let data = match prepareData(data, &config) {
+        Ok((data, _)) => data,
+        Err(message) => wasm_bindgen::throw_str(&message),
+    };
+
+    let info = data.info.unwrap_or(InfoStruct {
+        HI_LIMIT: None,
+        LO_LIMIT: None,
+        UNITS: None,
+    });
+
+    let rows: Vec<TooltipRowStruct> = data
+        .groups
+        .unwrap_or(vec![])
+        .get(group as usize)
+        .map(|item| {
+            // Same values as the plot, which depend on the x mode
+            let xMode = config.xMode.clone().unwrap_or(DEFAULT_X_MODE.to_string());
+            let filterGroups = filterGroup(applyXMode(vec![item.clone()], &xMode), vec![]);
+
+            groupTooltip(&filterGroups[0], index as usize, &info, &config)
+        })
+        .unwrap_or(vec![]);
+
+    serde_wasm_bindgen::to_value(&rows).unwrap()
+}
+
+/**
+ * Render the timeseries plot to a standalone SVG
+ * @method svgPlot
+ * @param {object} input_data
+ * @param {object} input_config - width and height in px
+ * @returns {string}
+ */
+#[wasm_bindgen]
+pub fn svgPlot(input_data: JsValue, input_config: JsValue) -> String {
+    let data: DataStruct = serde_wasm_bindgen::from_value(input_data).unwrap();
+    let config: ConfigStruct = serde_wasm_bindgen::from_value(input_config).unwrap();
+
+    match renderSvg(data, &config) {
+        Ok(svg) => svg,
+        Err(message) => wasm_bindgen::throw_str(&message),
+    }
+}
+
+/**
+ * Render the timeseries plot to PNG
+ * @method pngPlot
+ * @param {object} input_data
+ * @param {object} input_config - width and height in CSS px, dpi scales them to pixels
+ * @returns {Uint8Array}
+ */
+#[wasm_bindgen]
+pub fn pngPlot(input_data: JsValue, input_config: JsValue) -> Vec<u8> {
+    let data: DataStruct = serde_wasm_bindgen::from_value(input_data).unwrap();
+    let config: ConfigStruct = serde_wasm_bindgen::from_value(input_config).unwrap();
+
+    match renderPng(data, &config) {
+        Ok(png) => png,
+        Err(message) => wasm_bindgen::throw_str(&message),
+    }
+}
+
+/**
+ * Read CSV or TSV text into data, the result can be passed to main
+ * @method importCsv
+ * @param {string} text
+ * @param {object} input_config - csv options
+ * @returns {object}
+ */
+#[wasm_bindgen]
+pub fn importCsv(text: String, input_config: JsValue) -> JsValue {
+    let config: ConfigStruct = serde_wasm_bindgen::from_value(input_config).unwrap();
 
-    approx
+    match readCsv(&text, &config) {
+        Ok(data) => serde_wasm_bindgen::to_value(&data).unwrap(),
+        Err(message) => wasm_bindgen::throw_str(&message),
+    }
 }